@@ -0,0 +1,156 @@
+use crate::solver::Solution;
+use crate::Error;
+use crate::ErrorKind;
+use crate::Result;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const HEADER: &str = "event\tday\tinput\tpart\tanswer";
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key {
+    pub event: u16,
+    pub day: u8,
+    pub input: String,
+    pub part: u8,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<Key, String>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Missing { actual: String },
+    Mismatch { expected: String, actual: String },
+}
+
+pub fn digest(text: &str) -> String {
+    let hash = text.bytes().fold(0xcbf29ce484222325u64, |acc, byte| {
+        (acc ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+
+    format!("{hash:016x}")
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut answers = Answers::default();
+
+        for (index, line) in text.lines().enumerate() {
+            if line.is_empty() || line == HEADER {
+                continue;
+            }
+
+            let located = |error: Error| error.with_line(index + 1).with_text(line);
+            let split = line.split('\t').collect::<Vec<_>>();
+
+            let [event, day, input, part, answer] = split[..] else {
+                return Err(located(Error::syntax("Invalid answer record")));
+            };
+
+            let key = Key {
+                event: event.parse().map_err(|e| located(Error::from(e)))?,
+                day: day.parse().map_err(|e| located(Error::from(e)))?,
+                input: input.into(),
+                part: part.parse().map_err(|e| located(Error::from(e)))?,
+            };
+
+            answers.0.insert(key, answer.into());
+        }
+
+        Ok(answers)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        match fs::read_to_string(path.as_ref()) {
+            Ok(text) => Self::parse(&text),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                Err(Error::new(ErrorKind::Input, "Answers file not found")
+                    .with_text(path.as_ref().display().to_string()))
+            }
+            Err(error) => Err(Error::new(ErrorKind::Input, error.to_string())
+                .with_text(path.as_ref().display().to_string())),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path.as_ref(), self.to_string()).map_err(|error| {
+            Error::new(ErrorKind::Input, error.to_string())
+                .with_text(path.as_ref().display().to_string())
+        })
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    pub fn record(&mut self, solution: &Solution, input: &str) {
+        for record in solution.records() {
+            let key = Key {
+                event: record.event,
+                day: record.day,
+                input: input.into(),
+                part: record.part,
+            };
+
+            self.0.insert(key, record.answer.into());
+        }
+    }
+
+    pub fn check(&self, solution: &Solution, input: &str) -> [Status; 2] {
+        solution.records().map(|record| {
+            let key = Key {
+                event: record.event,
+                day: record.day,
+                input: input.into(),
+                part: record.part,
+            };
+
+            let actual = record.answer.to_owned();
+
+            match self.get(&key) {
+                None => Status::Missing { actual },
+                Some(expected) if expected == actual => Status::Pass,
+                Some(expected) => Status::Mismatch {
+                    expected: expected.into(),
+                    actual,
+                },
+            }
+        })
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+
+        for (key, answer) in self.0.iter() {
+            let Key {
+                event,
+                day,
+                input,
+                part,
+            } = key;
+
+            writeln!(f, "{event}\t{day}\t{input}\t{part}\t{answer}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Missing { actual } => write!(f, "missing (got {actual})"),
+            Status::Mismatch { expected, actual } => {
+                write!(f, "mismatch (expected {expected}, got {actual})")
+            }
+        }
+    }
+}
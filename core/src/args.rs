@@ -0,0 +1,71 @@
+use crate::Error;
+use crate::Result;
+use std::collections::BTreeMap;
+use std::env;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    options: BTreeMap<String, Option<String>>,
+    positional: Vec<String>,
+}
+
+impl Args {
+    pub fn new(args: impl IntoIterator<Item = String>) -> Self {
+        let mut result = Args::default();
+
+        for arg in args {
+            let Some(option) = arg.strip_prefix("--") else {
+                result.positional.push(arg);
+                continue;
+            };
+
+            let (key, value) = match option.split_once('=') {
+                Some((key, value)) => (key, Some(value.to_owned())),
+                None => (option, None),
+            };
+
+            result.options.insert(key.to_owned(), value);
+        }
+
+        result
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(env::args().skip(1))
+    }
+
+    pub fn allow(&self, names: &[&str]) -> Result<()> {
+        match self
+            .options
+            .keys()
+            .find(|key| !names.contains(&key.as_str()))
+        {
+            Some(key) => Err(Error::syntax(format!("Unknown option --{key}"))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn positional(&self) -> &[String] {
+        &self.positional
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options.get(name)?.as_deref()
+    }
+
+    pub fn parse<T: FromStr>(&self, name: &str) -> Result<Option<T>> {
+        let Some(text) = self.value(name) else {
+            return Ok(None);
+        };
+
+        match text.parse() {
+            Ok(value) => Ok(Some(value)),
+            Err(_) => Err(Error::value(format!("Invalid --{name} value")).with_text(text)),
+        }
+    }
+}
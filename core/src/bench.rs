@@ -0,0 +1,227 @@
+use crate::solver::Day;
+use crate::Error;
+use crate::ErrorKind;
+use crate::Result;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+use std::time::Instant;
+
+const HEADER: &str = "event\tday\tstage\tmedian";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    Parse,
+    One,
+    Two,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bench {
+    pub event: u16,
+    pub day: u8,
+    pub stages: [(Stage, Stats); 3],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Baseline(BTreeMap<(u16, u8, Stage), Duration>);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Xorshift(u64);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Comparison {
+    pub baseline: Duration,
+    pub change: f64,
+    pub regressed: bool,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Stage::Parse => "parse",
+            Stage::One => "one",
+            Stage::Two => "two",
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Stage {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            "one" => Ok(Stage::One),
+            "two" => Ok(Stage::Two),
+            _ => Err(Error::value("Unknown benchmark stage").with_text(s)),
+        }
+    }
+}
+
+impl Stats {
+    pub fn new(samples: &mut [Duration]) -> Option<Self> {
+        samples.sort();
+
+        Some(Stats {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+pub fn measure<T>(runs: usize, mut work: impl FnMut() -> T) -> Result<(T, Stats)> {
+    let mut samples = vec![];
+    let mut result = None;
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        result = Some(work());
+        samples.push(start.elapsed());
+    }
+
+    let stats = Stats::new(&mut samples);
+    let missing = || Error::value("No benchmark runs");
+
+    Ok((result.ok_or_else(missing)?, stats.ok_or_else(missing)?))
+}
+
+impl Xorshift {
+    pub fn new(seed: u64) -> Self {
+        Xorshift(seed.max(1))
+    }
+
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % bound.max(1)
+    }
+}
+
+impl Bench {
+    pub fn run(day: &Day, text: &str, runs: usize) -> Result<Self> {
+        let mut samples = [vec![], vec![], vec![]];
+
+        for _ in 0..runs {
+            let solution = (day.solve)(text)?;
+
+            samples[0].push(solution.parse);
+            samples[1].push(solution.one.elapsed);
+            samples[2].push(solution.two.elapsed);
+        }
+
+        let [parse, one, two] = samples.map(|mut list| Stats::new(&mut list));
+        let missing = || Error::value("No benchmark runs");
+
+        Ok(Bench {
+            event: day.event,
+            day: day.day,
+            stages: [
+                (Stage::Parse, parse.ok_or_else(missing)?),
+                (Stage::One, one.ok_or_else(missing)?),
+                (Stage::Two, two.ok_or_else(missing)?),
+            ],
+        })
+    }
+}
+
+impl Baseline {
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut baseline = Baseline::default();
+
+        for (index, line) in text.lines().enumerate() {
+            if line.is_empty() || line == HEADER {
+                continue;
+            }
+
+            let located = |error: Error| error.with_line(index + 1).with_text(line);
+            let split = line.split('\t').collect::<Vec<_>>();
+
+            let [event, day, stage, median] = split[..] else {
+                return Err(located(Error::syntax("Invalid baseline record")));
+            };
+
+            let key = (
+                event.parse().map_err(|e| located(Error::from(e)))?,
+                day.parse().map_err(|e| located(Error::from(e)))?,
+                stage.parse().map_err(located)?,
+            );
+
+            let nanos = median.parse().map_err(|e| located(Error::from(e)))?;
+            baseline.0.insert(key, Duration::from_nanos(nanos));
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        match fs::read_to_string(path.as_ref()) {
+            Ok(text) => Self::parse(&text),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                Err(Error::new(ErrorKind::Input, "Baseline file not found")
+                    .with_text(path.as_ref().display().to_string()))
+            }
+            Err(error) => Err(Error::new(ErrorKind::Input, error.to_string())
+                .with_text(path.as_ref().display().to_string())),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path.as_ref(), self.to_string()).map_err(|error| {
+            Error::new(ErrorKind::Input, error.to_string())
+                .with_text(path.as_ref().display().to_string())
+        })
+    }
+
+    pub fn record(&mut self, bench: &Bench) {
+        for (stage, stats) in bench.stages {
+            self.0.insert((bench.event, bench.day, stage), stats.median);
+        }
+    }
+
+    pub fn compare(&self, bench: &Bench, stage: Stage, threshold: f64) -> Option<Comparison> {
+        let baseline = *self.0.get(&(bench.event, bench.day, stage))?;
+        let (_, stats) = bench.stages.iter().find(|(s, _)| *s == stage)?;
+
+        let before = baseline.as_secs_f64();
+        let after = stats.median.as_secs_f64();
+        let change = if before > 0.0 {
+            after / before - 1.0
+        } else {
+            0.0
+        };
+
+        Some(Comparison {
+            baseline,
+            change,
+            regressed: change > threshold,
+        })
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+
+        for ((event, day, stage), median) in self.0.iter() {
+            writeln!(f, "{event}\t{day}\t{stage}\t{}", median.as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
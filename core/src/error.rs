@@ -0,0 +1,145 @@
+use crate::input::InputError;
+use std::borrow::Cow;
+use std::fmt;
+use std::num::ParseIntError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    Input,
+    Syntax,
+    Value,
+    Missing,
+    Unsolved,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    kind: ErrorKind,
+    message: Cow<'static, str>,
+    line: Option<usize>,
+    column: Option<usize>,
+    text: Option<String>,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn new(kind: ErrorKind, message: impl Into<Cow<'static, str>>) -> Self {
+        Error {
+            kind,
+            message: message.into(),
+            line: None,
+            column: None,
+            text: None,
+        }
+    }
+
+    pub fn syntax(message: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(ErrorKind::Syntax, message)
+    }
+
+    pub fn value(message: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(ErrorKind::Value, message)
+    }
+
+    pub fn missing(message: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(ErrorKind::Missing, message)
+    }
+
+    pub fn unsolved(message: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(ErrorKind::Unsolved, message)
+    }
+
+    /// Location setters keep the innermost value, so outer parsers can add
+    /// context without clobbering what a nested parser already reported.
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text.get_or_insert_with(|| text.into());
+        self
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ErrorKind::Input => "input",
+            ErrorKind::Syntax => "syntax",
+            ErrorKind::Value => "value",
+            ErrorKind::Missing => "missing",
+            ErrorKind::Unsolved => "unsolved",
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} error: {}", self.kind, self.message)?;
+
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, " at line {line}, column {column}")?,
+            (Some(line), None) => write!(f, " at line {line}")?,
+            (None, Some(column)) => write!(f, " at column {column}")?,
+            (None, None) => (),
+        }
+
+        match &self.text {
+            Some(text) => write!(f, ": {text:?}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseIntError> for Error {
+    fn from(value: ParseIntError) -> Self {
+        Error::value(format!("Invalid number ({value})"))
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(value: InputError) -> Self {
+        Error::new(ErrorKind::Input, value.to_string())
+    }
+}
+
+pub fn parse_lines<T>(input: &str, parse: impl Fn(&str) -> Result<T>) -> Result<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            parse(line).map_err(|error| error.with_line(index + 1).with_text(line))
+        })
+        .collect()
+}
@@ -0,0 +1,167 @@
+use crate::Error;
+use crate::Result;
+
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Wrap {
+    #[default]
+    None,
+    Horizontal,
+    Both,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    wrap: Wrap,
+}
+
+fn resolve(value: isize, size: usize, wrap: bool) -> Option<usize> {
+    match wrap {
+        true if size > 0 => Some(value.rem_euclid(size as isize) as usize),
+        _ => usize::try_from(value).ok().filter(|value| *value < size),
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, cells: Vec<T>) -> Result<Self> {
+        if !cells.len().is_multiple_of(width) {
+            return Err(Error::value("Cells do not fill the grid"));
+        }
+
+        let height = cells.len().checked_div(width).unwrap_or(0);
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+            wrap: Wrap::None,
+        })
+    }
+
+    pub fn parse(input: &str, mut parse: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut cells = vec![];
+        let mut width = None;
+
+        for (index, line) in input.lines().enumerate() {
+            let located = |error: Error| error.with_line(index + 1).with_text(line);
+            let start = cells.len();
+
+            for (column, chr) in line.chars().enumerate() {
+                cells.push(parse(chr).map_err(|error| located(error.with_column(column + 1)))?);
+            }
+
+            let length = cells.len() - start;
+
+            if *width.get_or_insert(length) != length {
+                return Err(located(Error::value("Ragged grid row")));
+            }
+        }
+
+        Grid::new(width.unwrap_or(0), cells)
+    }
+
+    pub fn with_wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn wrap(&self) -> Wrap {
+        self.wrap
+    }
+
+    pub fn locate(&self, row: isize, column: isize) -> Option<(usize, usize)> {
+        let (rows, columns) = match self.wrap {
+            Wrap::None => (false, false),
+            Wrap::Horizontal => (false, true),
+            Wrap::Both => (true, true),
+        };
+
+        let row = resolve(row, self.height, rows)?;
+        let column = resolve(column, self.width, columns)?;
+
+        Some((row, column))
+    }
+
+    fn index(&self, row: usize, column: usize) -> Option<usize> {
+        let row = isize::try_from(row).ok()?;
+        let column = isize::try_from(column).ok()?;
+        let (row, column) = self.locate(row, column)?;
+
+        Some(row * self.width + column)
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        let index = self.index(row, column)?;
+        self.cells.get(index)
+    }
+
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        let index = self.index(row, column)?;
+        self.cells.get_mut(index)
+    }
+
+    pub fn neighbours<'a>(
+        &'a self,
+        row: usize,
+        column: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)> + 'a {
+        offsets.iter().filter_map(move |(down, right)| {
+            let row = isize::try_from(row).ok()?.checked_add(*down)?;
+            let column = isize::try_from(column).ok()?.checked_add(*right)?;
+            let (row, column) = self.locate(row, column)?;
+
+            Some(((row, column), &self.cells[row * self.width + column]))
+        })
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        let start = self.index(row, 0)?;
+        self.cells.get(start..start + self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(move |row| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn column(&self, column: usize) -> Option<impl Iterator<Item = &T> + '_> {
+        let column = self.index(0, column)?;
+        Some(self.cells[column..].iter().step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(move |column| self.cells[column..].iter().step_by(self.width))
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        let width = self.width;
+
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| ((index / width, index % width), cell))
+    }
+}
@@ -0,0 +1,129 @@
+use crate::args::Args;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Path(PathBuf),
+    Default(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: Source,
+    error: io::Error,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Path(path) | Source::Default(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.source, self.error.kind()) {
+            (Source::Path(_), io::ErrorKind::NotFound) => write!(
+                f,
+                "Input file {} not found, pass a path or - to read from stdin",
+                self.source,
+            ),
+            (Source::Default(_), io::ErrorKind::NotFound) => write!(
+                f,
+                "Default input {} not found in the working directory or its parents, \
+                 pass a path or - to read from stdin",
+                self.source,
+            ),
+            _ => write!(
+                f,
+                "Could not read input from {}: {}",
+                self.source, self.error
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl Source {
+    pub fn new(arg: Option<&str>, default: &str) -> Self {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(path.into()),
+            None => Source::Default(locate(default)),
+        }
+    }
+
+    pub fn from_args(default: &str) -> Self {
+        let args = Args::from_env();
+        let input = args.positional().first().map(String::as_str);
+
+        Self::new(input, default)
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            Source::Path(path) | Source::Default(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text).map(|_| text)
+            }
+        };
+
+        result.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        let result = match self {
+            Source::Path(path) | Source::Default(path) => {
+                fs::File::open(path).map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+            }
+            Source::Stdin => Ok(Box::new(io::stdin().lock()) as Box<dyn BufRead>),
+        };
+
+        result.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+fn locate(default: &str) -> PathBuf {
+    let path = Path::new(default);
+    let found = env::current_dir().ok().and_then(|dir| {
+        dir.ancestors()
+            .map(|dir| dir.join(path))
+            .find(|candidate| candidate.is_file())
+    });
+
+    found.unwrap_or_else(|| path.into())
+}
+
+pub fn load(default: &str) -> String {
+    match Source::from_args(default).read() {
+        Ok(text) => text,
+        Err(error) => {
+            eprintln!("{error}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,16 @@
+pub mod answers;
+pub mod args;
+pub mod bench;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod solver;
+
+pub use error::Error;
+pub use error::ErrorKind;
+pub use error::Result;
+pub use solver::Solver;
+
+#[cfg(test)]
+mod tests;
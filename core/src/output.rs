@@ -0,0 +1,91 @@
+use crate::Error;
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Tsv,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub event: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: &'a str,
+    pub elapsed: Duration,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(Error::value("Unknown output format").with_text(s)),
+        }
+    }
+}
+
+impl Format {
+    pub fn header(&self) -> Option<&'static str> {
+        match self {
+            Format::Tsv => Some("event\tday\tpart\tanswer\telapsed"),
+            _ => None,
+        }
+    }
+
+    pub fn record(&self, record: &Record) -> String {
+        let Record {
+            event,
+            day,
+            part,
+            answer,
+            elapsed,
+        } = record;
+
+        let seconds = elapsed.as_secs_f64();
+
+        match self {
+            Format::Text => format!("{event} {day:02} part {part}: {answer} ({elapsed:.2?})"),
+            Format::Tsv => format!("{event}\t{day}\t{part}\t{}\t{seconds:.9}", escape_tsv(answer)),
+            Format::Json => format!(
+                "{{\"event\":{event},\"day\":{day},\"part\":{part},\"answer\":{},\"elapsed\":{seconds:.9}}}",
+                escape_json(answer),
+            ),
+        }
+    }
+}
+
+pub fn escape_json(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 2);
+    result.push('"');
+
+    for chr in text.chars() {
+        match chr {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            chr if chr.is_control() => write!(result, "\\u{:04x}", chr as u32).unwrap(),
+            chr => result.push(chr),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+pub fn escape_tsv(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
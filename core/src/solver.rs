@@ -0,0 +1,139 @@
+use crate::args::Args;
+use crate::input::Source;
+use crate::output::Format;
+use crate::output::Record;
+use crate::Error;
+use crate::Result;
+use std::fmt::Display;
+use std::process;
+use std::time::Duration;
+use std::time::Instant;
+
+pub trait Solver {
+    const EVENT: u16;
+    const DAY: u8;
+    const INPUT: &'static str;
+
+    type Input;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> Result<Self::Answer>;
+    fn part_two(input: &Self::Input) -> Result<Self::Answer>;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Part {
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Solution {
+    pub event: u16,
+    pub day: u8,
+    pub parse: Duration,
+    pub one: Part,
+    pub two: Part,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Day {
+    pub event: u16,
+    pub day: u8,
+    pub input: &'static str,
+    pub solve: fn(&str) -> Result<Solution>,
+}
+
+impl Day {
+    pub const fn new<S: Solver>() -> Self {
+        Day {
+            event: S::EVENT,
+            day: S::DAY,
+            input: S::INPUT,
+            solve: solve::<S>,
+        }
+    }
+
+    pub fn run(&self, source: &Source) -> Result<Solution> {
+        let text = source.read().map_err(Error::from)?;
+
+        (self.solve)(&text)
+    }
+}
+
+impl Part {
+    pub fn record(&self, event: u16, day: u8, part: u8) -> Record<'_> {
+        Record {
+            event,
+            day,
+            part,
+            answer: &self.answer,
+            elapsed: self.elapsed,
+        }
+    }
+}
+
+impl Solution {
+    pub fn records(&self) -> [Record<'_>; 2] {
+        [
+            self.one.record(self.event, self.day, 1),
+            self.two.record(self.event, self.day, 2),
+        ]
+    }
+}
+
+fn time<T>(func: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = func();
+
+    (value, start.elapsed())
+}
+
+fn part<A: Display>(func: impl FnOnce() -> Result<A>) -> Result<Part> {
+    let (answer, elapsed) = time(func);
+
+    Ok(Part {
+        answer: answer?.to_string(),
+        elapsed,
+    })
+}
+
+pub fn solve<S: Solver>(text: &str) -> Result<Solution> {
+    let (input, parse) = time(|| S::parse(text));
+    let input = input?;
+
+    Ok(Solution {
+        event: S::EVENT,
+        day: S::DAY,
+        parse,
+        one: part(|| S::part_one(&input))?,
+        two: part(|| S::part_two(&input))?,
+    })
+}
+
+pub fn run<S: Solver>() {
+    let args = Args::from_env();
+    let day = Day::new::<S>();
+
+    let result = args.allow(&["format"]).and_then(|_| {
+        let format = args.parse::<Format>("format")?;
+        let input = args.positional().first().map(String::as_str);
+        let solution = day.run(&Source::new(input, day.input))?;
+
+        Ok((format.unwrap_or_default(), solution))
+    });
+
+    let (format, solution) = result.unwrap_or_else(|error| {
+        eprintln!("Could not solve {} day {}: {error}", day.event, day.day);
+        process::exit(1);
+    });
+
+    if let Some(header) = format.header() {
+        println!("{header}");
+    }
+
+    for record in solution.records() {
+        println!("{}", format.record(&record));
+    }
+}
@@ -0,0 +1,308 @@
+use crate::answers::Answers;
+use crate::answers::Key;
+use crate::answers::Status;
+use crate::args::Args;
+use crate::bench::measure;
+use crate::bench::Baseline;
+use crate::bench::Bench;
+use crate::bench::Stage;
+use crate::bench::Stats;
+use crate::bench::Xorshift;
+use crate::error::parse_lines;
+use crate::grid::Grid;
+use crate::grid::Wrap;
+use crate::grid::ORTHOGONAL;
+use crate::grid::SURROUNDING;
+use crate::input::Source;
+use crate::output::Format;
+use crate::output::Record;
+use crate::solver::Part;
+use crate::solver::Solution;
+use crate::Error;
+use crate::ErrorKind;
+use std::env;
+use std::path::Path;
+use std::time::Duration;
+
+#[test]
+fn test_source() {
+    let default = "default.txt";
+
+    assert_eq!(Source::new(None, default), Source::Default(default.into()));
+    assert_eq!(
+        Source::new(None, "src/lib.rs"),
+        Source::Default(env::current_dir().unwrap().join("src/lib.rs"))
+    );
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    assert_eq!(
+        Source::new(None, "core/src/lib.rs"),
+        Source::Default(root.join("core/src/lib.rs"))
+    );
+    assert_eq!(
+        Source::new(Some("x.txt"), default),
+        Source::Path("x.txt".into())
+    );
+    assert_eq!(Source::new(Some("-"), default), Source::Stdin);
+}
+
+#[test]
+fn test_missing() {
+    let source = Source::new(Some("missing.txt"), "");
+    let error = source.read().unwrap_err();
+
+    assert!(error.to_string().contains("missing.txt not found"));
+
+    let error = Source::new(None, "input/missing.txt").read().unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Default input input/missing.txt not found"));
+
+    let error = Answers::load("missing.tsv").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Input);
+    assert_eq!(error.text(), Some("missing.tsv"));
+
+    let error = Baseline::load("missing.tsv").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Input);
+    assert_eq!(error.text(), Some("missing.tsv"));
+}
+
+#[test]
+fn test_error_display() {
+    let error = Error::syntax("Invalid divider")
+        .with_line(3)
+        .with_column(7)
+        .with_text("1-3 a b");
+
+    let text = "syntax error: Invalid divider at line 3, column 7: \"1-3 a b\"";
+
+    assert_eq!(error.to_string(), text);
+}
+
+#[test]
+fn test_error_context() {
+    let error = Error::value("Invalid value").with_line(1).with_line(2);
+
+    assert_eq!(error.kind(), ErrorKind::Value);
+    assert_eq!(error.line(), Some(1));
+}
+
+#[test]
+fn test_parse_lines() {
+    let error = parse_lines("1\n2\nx", |line| Ok(line.parse::<i32>()?)).unwrap_err();
+
+    assert_eq!(error.kind(), ErrorKind::Value);
+    assert_eq!(error.line(), Some(3));
+    assert_eq!(error.text(), Some("x"));
+}
+
+#[test]
+fn test_args() {
+    let list = ["2020", "--format=json", "--check", "-"];
+    let args = Args::new(list.iter().map(|arg| arg.to_string()));
+
+    assert_eq!(args.positional(), ["2020", "-"]);
+    assert_eq!(args.value("format"), Some("json"));
+    assert_eq!(args.parse::<Format>("format").unwrap(), Some(Format::Json));
+    assert!(args.flag("check"));
+    assert!(!args.flag("format=json"));
+
+    assert!(args.allow(&["format", "check", "record"]).is_ok());
+    let error = args.allow(&["format"]).unwrap_err();
+    assert_eq!(error.message(), "Unknown option --check");
+}
+
+#[test]
+fn test_records() {
+    let record = Record {
+        event: 2020,
+        day: 8,
+        part: 2,
+        answer: "a\"b",
+        elapsed: Duration::from_millis(1500),
+    };
+
+    let json = r#"{"event":2020,"day":8,"part":2,"answer":"a\"b","elapsed":1.500000000}"#;
+    let tsv = "2020\t8\t2\ta\"b\t1.500000000";
+
+    assert_eq!(Format::Json.record(&record), json);
+    assert_eq!(Format::Tsv.record(&record), tsv);
+}
+
+#[test]
+fn test_answers() {
+    let text = "event\tday\tinput\tpart\tanswer\n2020\t1\tabc\t1\t42\n";
+    let answers = Answers::parse(text).unwrap();
+
+    let part = |answer: &str| Part {
+        answer: answer.into(),
+        elapsed: Duration::ZERO,
+    };
+
+    let solution = Solution {
+        event: 2020,
+        day: 1,
+        parse: Duration::ZERO,
+        one: part("41"),
+        two: part("7"),
+    };
+
+    let key = Key {
+        event: 2020,
+        day: 1,
+        input: "abc".into(),
+        part: 1,
+    };
+
+    let mismatch = Status::Mismatch {
+        expected: "42".into(),
+        actual: "41".into(),
+    };
+
+    let missing = Status::Missing { actual: "7".into() };
+
+    assert_eq!(answers.get(&key), Some("42"));
+    assert_eq!(answers.check(&solution, "abc"), [mismatch, missing]);
+    assert_eq!(answers.to_string(), text);
+}
+
+#[test]
+fn test_stats() {
+    let mut samples = [5, 1, 4, 2, 3].map(Duration::from_micros);
+    let stats = Stats::new(&mut samples).unwrap();
+
+    assert_eq!(stats.min, Duration::from_micros(1));
+    assert_eq!(stats.median, Duration::from_micros(3));
+    assert_eq!(stats.max, Duration::from_micros(5));
+    assert_eq!(Stats::new(&mut []), None);
+}
+
+#[test]
+fn test_baseline() {
+    let stats = |micros| {
+        let time = Duration::from_micros(micros);
+
+        Stats {
+            min: time,
+            median: time,
+            max: time,
+        }
+    };
+
+    let before = Bench {
+        event: 2020,
+        day: 1,
+        stages: [
+            (Stage::Parse, stats(100)),
+            (Stage::One, stats(100)),
+            (Stage::Two, stats(100)),
+        ],
+    };
+
+    let after = Bench {
+        stages: [
+            (Stage::Parse, stats(105)),
+            (Stage::One, stats(150)),
+            (Stage::Two, stats(50)),
+        ],
+        ..before.clone()
+    };
+
+    let mut baseline = Baseline::default();
+    baseline.record(&before);
+
+    let baseline = Baseline::parse(&baseline.to_string()).unwrap();
+    let compare = |stage| baseline.compare(&after, stage, 0.1).unwrap().regressed;
+
+    assert!(!compare(Stage::Parse));
+    assert!(compare(Stage::One));
+    assert!(!compare(Stage::Two));
+}
+
+fn digits(input: &str) -> Result<Grid<u32>, Error> {
+    Grid::parse(input, |chr| {
+        chr.to_digit(10)
+            .ok_or_else(|| Error::value("Invalid digit").with_text(chr))
+    })
+}
+
+#[test]
+fn test_grid() {
+    let grid = digits("123\n456").unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(1, 2), Some(&6));
+    assert_eq!(grid.get(1, 3), None);
+    assert_eq!(grid.get(2, 0), None);
+    assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+    assert_eq!(grid.column(1).unwrap().collect::<Vec<_>>(), [&2, &5]);
+    assert_eq!(grid.rows().count(), 2);
+    assert_eq!(
+        grid.columns()
+            .map(|column| column.sum::<u32>())
+            .collect::<Vec<_>>(),
+        [5, 7, 9]
+    );
+    assert_eq!(grid.cells().nth(4), Some(((1, 1), &5)));
+
+    let around = |grid: &Grid<u32>, offsets| {
+        let cells = grid.neighbours(0, 0, offsets).map(|(_, cell)| *cell);
+        cells.collect::<Vec<_>>()
+    };
+
+    assert_eq!(around(&grid, &ORTHOGONAL), [2, 4]);
+    assert_eq!(around(&grid, &SURROUNDING), [2, 4, 5]);
+
+    let grid = grid.with_wrap(Wrap::Horizontal);
+    assert_eq!(grid.get(1, 4), Some(&5));
+    assert_eq!(grid.get(2, 0), None);
+    assert_eq!(around(&grid, &ORTHOGONAL), [3, 2, 4]);
+
+    let grid = grid.with_wrap(Wrap::Both);
+    assert_eq!(grid.get(2, 4), Some(&2));
+    assert_eq!(grid.locate(-1, -1), Some((1, 2)));
+    assert_eq!(around(&grid, &ORTHOGONAL), [4, 3, 2, 4]);
+}
+
+#[test]
+fn test_grid_errors() {
+    let error = digits("123\n4x6").unwrap_err();
+    assert_eq!((error.line(), error.column()), (Some(2), Some(2)));
+    assert_eq!(error.text(), Some("x"));
+
+    let error = digits("123\n45\n789").unwrap_err();
+    assert_eq!(error.message(), "Ragged grid row");
+    assert_eq!(error.line(), Some(2));
+
+    assert_eq!(digits("").unwrap().height(), 0);
+    assert!(Grid::new(2, vec![1, 2, 3]).is_err());
+    assert!(Grid::<u8>::new(0, vec![]).is_ok());
+}
+
+#[test]
+fn test_measure() {
+    let mut calls = 0;
+    let (result, stats) = measure(3, || {
+        calls += 1;
+        calls * 2
+    })
+    .unwrap();
+
+    assert_eq!((calls, result), (3, 6));
+    assert!(stats.min <= stats.median && stats.median <= stats.max);
+    assert!(measure(0, || ()).is_err());
+}
+
+#[test]
+fn test_xorshift() {
+    let mut random = Xorshift::new(0);
+    let first = (0..8).map(|_| random.below(10)).collect::<Vec<_>>();
+
+    assert!(first.iter().all(|value| *value < 10));
+    assert_eq!(Xorshift::new(1), Xorshift::new(0));
+    assert_ne!(
+        Xorshift::new(2).below(u64::MAX),
+        Xorshift::new(3).below(u64::MAX)
+    );
+    assert_eq!(Xorshift::new(5).below(0), 0);
+}
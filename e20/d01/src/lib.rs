@@ -0,0 +1,48 @@
+use advent_core::error::parse_lines;
+use advent_core::Error;
+use advent_core::Result;
+use advent_core::Solver;
+
+pub use self::sums::*;
+
+mod sums;
+
+#[cfg(test)]
+mod tests;
+
+const INPUT: &str = "input/2020/01.txt";
+pub const TARGET: i64 = 2020;
+
+pub fn parse(input: &str) -> Result<Vec<i64>> {
+    parse_lines(input, |line| Ok(line.trim().parse::<i64>()?))
+}
+
+pub fn solve(values: &[i64], k: usize, target: i64) -> Result<i64> {
+    let found = find_k_sum(values, k, target)
+        .ok_or_else(|| Error::unsolved(format!("Could not find {k} values summing to {target}")))?;
+
+    found.product()
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const EVENT: u16 = 2020;
+    const DAY: u8 = 1;
+    const INPUT: &'static str = INPUT;
+
+    type Input = Vec<i64>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(values: &Self::Input) -> Result<Self::Answer> {
+        solve(values, 2, TARGET)
+    }
+
+    fn part_two(values: &Self::Input) -> Result<Self::Answer> {
+        solve(values, 3, TARGET)
+    }
+}
@@ -1,48 +1,60 @@
-use std::num::ParseIntError;
-
-const INPUT: &str = include_str!("input.txt");
-const TARGET: i32 = 2020;
-
-fn parse() -> Result<Vec<i32>, ParseIntError> {
-    INPUT.lines().map(|line| line.parse::<i32>()).collect()
-}
-
-fn find_pair(values: &[i32]) -> Option<(i32, i32)> {
-    for x in values.iter() {
-        let y = TARGET - x;
-
-        if values.binary_search(&y).is_ok() {
-            return Some((*x, y));
-        }
-    }
-
-    None
-}
-
-fn find_triplet(values: &[i32]) -> Option<(i32, i32, i32)> {
-    for (i, x) in values.iter().enumerate() {
-        let (_, split) = values.split_at(i);
-
-        for (j, y) in split.iter().enumerate() {
-            let (_, split) = values.split_at(j);
-            let z = TARGET - x - y;
-
-            if split.binary_search(&z).is_ok() {
-                return Some((*x, *y, z));
+use advent_core::args::Args;
+use advent_core::input::Source;
+use advent_core::Result;
+use advent_core::Solver;
+use e20d01::Puzzle;
+use std::process;
+
+const OPTIONS: &[&str] = &["format", "lines", "all", "count", "target", "k"];
+
+fn audit(args: &Args) -> Result<()> {
+    let input = args.positional().first().map(String::as_str);
+    let text = Source::new(input, Puzzle::INPUT).read()?;
+    let values = e20d01::parse(&text)?;
+
+    let target = args.parse::<i64>("target")?.unwrap_or(e20d01::TARGET);
+
+    let sizes = match args.parse::<usize>("k")? {
+        Some(k) => vec![k],
+        None => vec![2, 3],
+    };
+
+    for k in sizes {
+        if args.flag("count") {
+            let count = e20d01::count_k_sums(&values, k, target);
+            println!("Found {k}: {count} combinations");
+        } else if args.flag("all") {
+            for combination in e20d01::k_sums(&values, k, target) {
+                println!("Found {k}: {combination}");
+            }
+        } else {
+            match e20d01::find_k_sum(&values, k, target) {
+                Some(found) => println!("Found {k}: {found} => {}", found.product()?),
+                None => println!("Found {k}: none"),
             }
         }
     }
 
-    None
+    Ok(())
 }
 
 fn main() {
-    let mut values = parse().expect("Input not all numbers.");
-    values.sort();
+    let args = Args::from_env();
 
-    let pair = find_pair(&values).expect("Could not find pair.");
-    println!("Target Pair: {}", pair.0 * pair.1);
+    if let Err(error) = args.allow(OPTIONS) {
+        eprintln!("{error}");
+        process::exit(1);
+    }
+
+    if !["lines", "all", "count", "target", "k"]
+        .iter()
+        .any(|flag| args.flag(flag))
+    {
+        return advent_core::solver::run::<Puzzle>();
+    }
 
-    let triplet = find_triplet(&values).expect("Cound not find triplet.");
-    println!("Target Triplet: {}", triplet.0 * triplet.1 * triplet.2);
+    if let Err(error) = audit(&args) {
+        eprintln!("{error}");
+        process::exit(1);
+    }
 }
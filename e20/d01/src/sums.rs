@@ -0,0 +1,181 @@
+use advent_core::Error;
+use advent_core::Result;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Combination {
+    pub indices: Vec<usize>,
+    pub values: Vec<i64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct KSums<'a> {
+    values: &'a [i64],
+    sorted: Vec<(i64, usize)>,
+    prefix: Vec<i128>,
+    k: usize,
+    target: i128,
+    stack: Vec<usize>,
+    sum: i128,
+    done: bool,
+}
+
+impl Combination {
+    pub fn lines(&self) -> impl Iterator<Item = usize> + '_ {
+        self.indices.iter().map(|index| index + 1)
+    }
+
+    pub fn product(&self) -> Result<i64> {
+        self.values.iter().try_fold(1i64, |acc, value| {
+            acc.checked_mul(*value).ok_or_else(|| {
+                Error::value("Product overflows 64 bits").with_text(self.to_string())
+            })
+        })
+    }
+}
+
+impl fmt::Display for Combination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms = self
+            .values
+            .iter()
+            .zip(self.lines())
+            .map(|(value, line)| format!("{value} (line {line})"))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", terms.join(" + "))
+    }
+}
+
+impl<'a> KSums<'a> {
+    pub fn new(values: &'a [i64], k: usize, target: i64) -> Self {
+        let mut sorted = values
+            .iter()
+            .copied()
+            .zip(0..)
+            .collect::<Vec<(i64, usize)>>();
+
+        sorted.sort_unstable();
+
+        let prefix = sorted
+            .iter()
+            .scan(0, |acc, (value, _)| {
+                *acc += i128::from(*value);
+                Some(*acc)
+            })
+            .collect::<Vec<_>>();
+
+        let prefix = [&[0][..], &prefix].concat();
+        let target = i128::from(target);
+        let done = values.len() < k || (k == 0 && target != 0);
+
+        KSums {
+            values,
+            sorted,
+            prefix,
+            k,
+            target,
+            stack: Vec::with_capacity(k.min(values.len())),
+            sum: 0,
+            done,
+        }
+    }
+
+    fn value(&self, position: usize) -> i128 {
+        i128::from(self.sorted[position].0)
+    }
+
+    fn candidate(&self, start: usize) -> Option<usize> {
+        let len = self.sorted.len();
+        let rest = self.k - self.stack.len() - 1;
+        let end = len.checked_sub(rest)?;
+
+        if start >= end {
+            return None;
+        }
+
+        let largest = self.prefix[len] - self.prefix[len - rest];
+        let needed = self.target - self.sum - largest;
+
+        let offset = self.sorted[start..end].partition_point(|pair| i128::from(pair.0) < needed);
+        let position = start + offset;
+
+        if position >= end {
+            return None;
+        }
+
+        let smallest = self.prefix[position + 1 + rest] - self.prefix[position + 1];
+
+        if self.sum + self.value(position) + smallest <= self.target {
+            Some(position)
+        } else {
+            None
+        }
+    }
+
+    fn push(&mut self, position: usize) {
+        self.sum += self.value(position);
+        self.stack.push(position);
+    }
+
+    fn backtrack(&mut self) -> bool {
+        while let Some(position) = self.stack.pop() {
+            self.sum -= self.value(position);
+
+            if let Some(next) = self.candidate(position + 1) {
+                self.push(next);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn combination(&self) -> Combination {
+        let mut indices = self
+            .stack
+            .iter()
+            .map(|position| self.sorted[*position].1)
+            .collect::<Vec<_>>();
+
+        indices.sort_unstable();
+        let values = indices.iter().map(|index| self.values[*index]).collect();
+
+        Combination { indices, values }
+    }
+}
+
+impl Iterator for KSums<'_> {
+    type Item = Combination;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            if self.stack.len() == self.k {
+                let combination = self.combination();
+                self.done = !self.backtrack();
+                return Some(combination);
+            }
+
+            let start = self.stack.last().map_or(0, |position| position + 1);
+
+            match self.candidate(start) {
+                Some(position) => self.push(position),
+                None => self.done = !self.backtrack(),
+            }
+        }
+
+        None
+    }
+}
+
+pub fn k_sums(values: &[i64], k: usize, target: i64) -> KSums<'_> {
+    KSums::new(values, k, target)
+}
+
+pub fn find_k_sum(values: &[i64], k: usize, target: i64) -> Option<Combination> {
+    k_sums(values, k, target).next()
+}
+
+pub fn count_k_sums(values: &[i64], k: usize, target: i64) -> usize {
+    k_sums(values, k, target).count()
+}
@@ -0,0 +1,129 @@
+use crate::count_k_sums;
+use crate::find_k_sum;
+use crate::k_sums;
+use crate::Combination;
+use advent_core::bench::Xorshift;
+
+const SAMPLE: &[i64] = &[1721, 979, 366, 299, 675, 1456];
+
+#[test]
+fn test_pair() {
+    let actual = find_k_sum(SAMPLE, 2, 2020);
+
+    let expect = Some(Combination {
+        indices: vec![0, 3],
+        values: vec![1721, 299],
+    });
+
+    assert_eq!(actual, expect);
+}
+
+#[test]
+fn test_triplet() {
+    let actual = find_k_sum(SAMPLE, 3, 2020);
+
+    let expect = Some(Combination {
+        indices: vec![1, 2, 4],
+        values: vec![979, 366, 675],
+    });
+
+    assert_eq!(actual, expect);
+}
+
+#[test]
+fn test_larger() {
+    let actual = find_k_sum(SAMPLE, 4, 366 + 299 + 675 + 1456).unwrap();
+
+    assert_eq!(actual.indices, vec![2, 3, 4, 5]);
+    assert_eq!(find_k_sum(SAMPLE, 4, 1), None);
+    assert_eq!(find_k_sum(SAMPLE, 7, 5496), None);
+    assert_eq!(find_k_sum(SAMPLE, usize::MAX, 0), None);
+}
+
+fn range(random: &mut Xorshift, lower: i64, upper: i64) -> i64 {
+    lower + random.below((upper - lower) as u64) as i64
+}
+
+fn oracle(values: &[i64], k: usize, target: i64, start: usize) -> usize {
+    if k == 0 {
+        return (target == 0) as usize;
+    }
+
+    (start..values.len())
+        .map(|i| oracle(values, k - 1, target - values[i], i + 1))
+        .sum()
+}
+
+#[test]
+fn test_reuse() {
+    assert_eq!(find_k_sum(&[1010, 0, 5], 3, 2020), None);
+    assert_eq!(find_k_sum(&[1010, 7], 2, 2020), None);
+    assert_eq!(find_k_sum(&[1010, 1010], 2, 2020).unwrap().indices, [0, 1]);
+}
+
+#[test]
+fn test_oracle() {
+    let mut random = Xorshift::new(0x2020_0001);
+
+    for _ in 0..2000 {
+        let len = range(&mut random, 0, 10) as usize;
+        let k = range(&mut random, 0, 5) as usize;
+        let target = range(&mut random, -40, 40);
+
+        let values = (0..len)
+            .map(|_| range(&mut random, -20, 20))
+            .collect::<Vec<_>>();
+
+        let actual = find_k_sum(&values, k, target);
+
+        let Some(found) = actual else {
+            assert_eq!(
+                oracle(&values, k, target, 0),
+                0,
+                "{:?} {} {}",
+                values,
+                k,
+                target
+            );
+            continue;
+        };
+
+        let picked = found.indices.iter().map(|i| values[*i]).collect::<Vec<_>>();
+
+        assert_eq!(found.indices.len(), k);
+        assert!(found.indices.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(found.values, picked);
+        assert_eq!(found.values.iter().sum::<i64>(), target);
+        assert_eq!(
+            count_k_sums(&values, k, target),
+            oracle(&values, k, target, 0)
+        );
+    }
+}
+
+#[test]
+fn test_all() {
+    let values = [1, 2, 3, 4, 3];
+
+    let actual = k_sums(&values, 2, 6)
+        .map(|found| found.indices)
+        .collect::<Vec<_>>();
+
+    assert_eq!(actual, vec![vec![1, 3], vec![2, 4]]);
+    assert_eq!(count_k_sums(&values, 3, 9), 2);
+    assert_eq!(count_k_sums(&values, 0, 0), 1);
+    assert_eq!(count_k_sums(&values, 6, 13), 0);
+}
+
+#[test]
+fn test_wide() {
+    let values = [i64::MAX, i64::MIN, -5, 5, 5];
+
+    let found = find_k_sum(&values, 2, -1).unwrap();
+    assert_eq!(found.values, [i64::MAX, i64::MIN]);
+    assert!(found.product().is_err());
+
+    assert_eq!(count_k_sums(&values, 2, 0), 2);
+    assert_eq!(count_k_sums(&values, 3, i64::MAX), 2);
+    assert_eq!(find_k_sum(&values, 3, 5).unwrap().product().unwrap(), -125);
+}
@@ -0,0 +1,295 @@
+use super::pattern::escaped;
+use super::pattern::Chars;
+use super::pattern::Class;
+use super::unit::Password;
+use super::PasswordPolicy;
+use super::Policy;
+use advent_core::Error;
+use advent_core::Result;
+use std::fmt;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Rule(Policy),
+    Length(usize, usize),
+    Not(Box<Expr>),
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+}
+
+pub(crate) struct Parser<'a> {
+    chars: Chars<'a>,
+    width: usize,
+}
+
+impl Expr {
+    pub fn eval(&self, policy: &dyn PasswordPolicy, password: &Password) -> bool {
+        match self {
+            Expr::Rule(rule) => policy.check(rule, password),
+            Expr::Length(min, max) => (*min..=*max).contains(&password.len()),
+            Expr::Not(expr) => !expr.eval(policy, password),
+            Expr::And(list) => list.iter().all(|expr| expr.eval(policy, password)),
+            Expr::Or(list) => list.iter().any(|expr| expr.eval(policy, password)),
+        }
+    }
+
+    pub fn verify(&self, policy: &dyn PasswordPolicy) -> Result<()> {
+        match self {
+            Expr::Rule(rule) => policy.verify(rule),
+            Expr::Length(..) => Ok(()),
+            Expr::Not(expr) => expr.verify(policy),
+            Expr::And(list) | Expr::Or(list) => {
+                list.iter().try_for_each(|expr| expr.verify(policy))
+            }
+        }
+    }
+
+    pub fn explain(&self, policy: &dyn PasswordPolicy, password: &Password) -> Option<String> {
+        match self {
+            Expr::Rule(rule) => policy.explain(rule, password),
+            Expr::Length(min, max) => {
+                let length = password.len();
+                let valid = (*min..=*max).contains(&length);
+
+                (!valid).then(|| format!("length {length} but allowed {min}-{max}"))
+            }
+            Expr::Not(expr) => match expr.explain(policy, password) {
+                Some(_) => None,
+                None => Some(format!("{expr} holds but is negated")),
+            },
+            Expr::And(list) => {
+                let reasons = list
+                    .iter()
+                    .filter_map(|expr| expr.explain(policy, password))
+                    .collect::<Vec<_>>();
+
+                (!reasons.is_empty()).then(|| reasons.join("; "))
+            }
+            Expr::Or(list) => {
+                let reasons = list
+                    .iter()
+                    .map(|expr| expr.explain(policy, password))
+                    .collect::<Option<Vec<_>>>()?;
+
+                Some(format!("none of: {}", reasons.join(" | ")))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nested = |expr: &Expr| match expr {
+            Expr::And(_) | Expr::Or(_) => format!("({expr})"),
+            expr => expr.to_string(),
+        };
+
+        match self {
+            Expr::Rule(rule) => write!(f, "{}-{} {}", rule.min, rule.max, rule.target),
+            Expr::Length(min, max) => write!(f, "len {min}-{max}"),
+            Expr::Not(expr) => write!(f, "not {}", nested(expr)),
+            Expr::And(list) => {
+                let list = list.iter().map(nested).collect::<Vec<_>>();
+                write!(f, "{}", list.join(" and "))
+            }
+            Expr::Or(list) => {
+                let list = list.iter().map(nested).collect::<Vec<_>>();
+                write!(f, "{}", list.join(" or "))
+            }
+        }
+    }
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        Parser {
+            chars: text.chars().enumerate().peekable(),
+            width: text.chars().count(),
+        }
+    }
+
+    fn column(&mut self) -> usize {
+        self.chars.peek().map_or(self.width, |(column, _)| *column) + 1
+    }
+
+    fn error(&mut self, error: Error) -> Error {
+        let column = self.column();
+        error.with_column(column)
+    }
+
+    fn skip(&mut self) {
+        while self.chars.next_if(|(_, chr)| chr.is_whitespace()).is_some() {}
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip();
+        self.chars.peek().map(|(_, chr)| *chr)
+    }
+
+    fn symbol(&mut self, symbols: &str) -> bool {
+        match self.peek() {
+            Some(chr) if symbols.contains(chr) => self.chars.next().is_some(),
+            _ => false,
+        }
+    }
+
+    fn keyword(&mut self, word: &str) -> bool {
+        self.skip();
+
+        let mut ahead = self.chars.clone();
+        let matched = word
+            .chars()
+            .all(|chr| ahead.next().map(|(_, c)| c) == Some(chr));
+        let boundary = ahead.peek().is_none_or(|(_, chr)| !chr.is_alphanumeric());
+
+        if matched && boundary {
+            self.chars = ahead;
+        }
+
+        matched && boundary
+    }
+
+    fn number(&mut self) -> Result<usize> {
+        self.skip();
+
+        let column = self.column();
+        let mut digits = String::new();
+
+        while let Some((_, chr)) = self.chars.next_if(|(_, chr)| chr.is_ascii_digit()) {
+            digits.push(chr);
+        }
+
+        digits.parse().map_err(|_| {
+            Error::syntax("Expected number")
+                .with_column(column)
+                .with_text(digits)
+        })
+    }
+
+    fn range(&mut self) -> Result<(usize, usize)> {
+        let min = self.number()?;
+
+        if !self.symbol("-") {
+            return Ok((min, min));
+        }
+
+        Ok((min, self.number()?))
+    }
+
+    fn class(&mut self) -> Result<Class> {
+        self.skip();
+
+        match self.chars.next() {
+            Some((open, '[')) => Class::parse_bracket(&mut self.chars, open),
+            Some((column, '\\')) => Ok(Class::single(escaped(&mut self.chars, column)?)),
+            Some((_, ':')) | None => Err(self.error(Error::syntax("Missing target"))),
+            Some((_, chr)) => Ok(Class::literal(&self.cluster(chr))),
+        }
+    }
+
+    fn cluster(&mut self, chr: char) -> String {
+        let mut cluster = chr.to_string();
+
+        while let Some((_, next)) = self.chars.peek() {
+            let joined = format!("{cluster}{next}");
+
+            if joined.graphemes(true).nth(1).is_some() {
+                break;
+            }
+
+            cluster = joined;
+            self.chars.next();
+        }
+
+        cluster
+    }
+
+    fn clause(&mut self) -> Result<Expr> {
+        if self.keyword("len") {
+            self.skip();
+
+            let column = self.column();
+            let (min, max) = self.range()?;
+
+            if min > max {
+                return Err(Error::value("Inverted length range").with_column(column));
+            }
+
+            return Ok(Expr::Length(min, max));
+        }
+
+        self.skip();
+
+        let column = self.column();
+        let (min, max) = self.range()?;
+
+        if min > max {
+            return Err(Error::value("Inverted rule range").with_column(column));
+        }
+
+        let target = self.class()?;
+
+        Ok(Expr::Rule(Policy { target, min, max }))
+    }
+
+    fn unary(&mut self) -> Result<Expr> {
+        if self.keyword("not") || self.symbol("!") {
+            return Ok(Expr::Not(Box::new(self.unary()?)));
+        }
+
+        if !self.symbol("(") {
+            return self.clause();
+        }
+
+        let expr = self.expr()?;
+
+        if self.symbol(")") {
+            Ok(expr)
+        } else {
+            Err(self.error(Error::syntax("Unclosed parenthesis")))
+        }
+    }
+
+    fn and(&mut self) -> Result<Expr> {
+        let mut list = vec![self.unary()?];
+
+        while self.keyword("and") || self.symbol("&,;") {
+            list.push(self.unary()?);
+        }
+
+        Ok(match list.len() {
+            1 => list.remove(0),
+            _ => Expr::And(list),
+        })
+    }
+
+    pub(crate) fn expr(&mut self) -> Result<Expr> {
+        let mut list = vec![self.and()?];
+
+        while self.keyword("or") || self.symbol("|") {
+            list.push(self.and()?);
+        }
+
+        Ok(match list.len() {
+            1 => list.remove(0),
+            _ => Expr::Or(list),
+        })
+    }
+
+    pub(crate) fn divider(&mut self) -> Result<()> {
+        if self.symbol(":") {
+            Ok(())
+        } else {
+            Err(self.error(Error::syntax("Invalid password divider")))
+        }
+    }
+
+    pub(crate) fn rest(self) -> String {
+        self.chars
+            .map(|(_, chr)| chr)
+            .collect::<String>()
+            .trim()
+            .to_owned()
+    }
+}
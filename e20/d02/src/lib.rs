@@ -0,0 +1,109 @@
+use advent_core::Error;
+use advent_core::Result;
+use advent_core::Solver;
+use std::str::FromStr;
+
+pub use self::grammar::Expr;
+pub use self::parallel::*;
+pub use self::pattern::Class;
+pub use self::pattern::Pattern;
+pub use self::policy::*;
+pub use self::report::*;
+pub use self::stream::*;
+pub use self::unit::Password;
+pub use self::unit::Unit;
+
+use self::grammar::Parser;
+
+mod grammar;
+mod parallel;
+mod pattern;
+mod policy;
+mod report;
+mod stream;
+mod unit;
+
+#[cfg(test)]
+mod tests;
+
+const INPUT: &str = "input/2020/02.txt";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Policy {
+    pub target: Class,
+    pub min: usize,
+    pub max: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub expr: Expr,
+    pub password: String,
+}
+
+impl FromStr for Entry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parser = Parser::new(s);
+
+        let expr = parser.expr()?;
+        parser.divider()?;
+        let password = parser.rest();
+
+        Ok(Entry { expr, password })
+    }
+}
+
+fn parse_line(line: (usize, &str)) -> Result<Entry> {
+    let (number, text) = line;
+
+    text.parse()
+        .map_err(|error: Error| error.with_line(number + 1).with_text(text))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Entry>> {
+    input.lines().enumerate().map(parse_line).collect()
+}
+
+pub fn verify(entries: &[Entry], policy: &dyn PasswordPolicy) -> Result<()> {
+    entries.iter().enumerate().try_for_each(|(index, entry)| {
+        let located = |error: Error| error.with_line(index + 1).with_text(entry.expr.to_string());
+        entry.expr.verify(policy).map_err(located)
+    })
+}
+
+pub fn count_valid(entries: &[Entry], policy: &dyn PasswordPolicy, unit: Unit) -> usize {
+    entries
+        .iter()
+        .filter(|entry| {
+            entry
+                .expr
+                .eval(policy, &Password::new(&entry.password, unit))
+        })
+        .count()
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const EVENT: u16 = 2020;
+    const DAY: u8 = 2;
+    const INPUT: &'static str = INPUT;
+
+    type Input = Vec<Entry>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(entries: &Self::Input) -> Result<Self::Answer> {
+        Ok(count_valid(entries, &CountRange, Unit::Char))
+    }
+
+    fn part_two(entries: &Self::Input) -> Result<Self::Answer> {
+        verify(entries, &ExactlyOne)?;
+        Ok(count_valid(entries, &ExactlyOne, Unit::Char))
+    }
+}
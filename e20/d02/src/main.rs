@@ -1,132 +1,147 @@
-use std::str::FromStr;
-
-const INPUT: &str = include_str!("input.txt");
-
-#[allow(dead_code)]
-#[derive(Debug)]
-enum ErrorType {
-    EntryError(&'static str),
-    PolicyError(&'static str),
-}
+use advent_core::args::Args;
+use advent_core::bench::measure;
+use advent_core::input::Source;
+use advent_core::output::Format;
+use advent_core::Error;
+use advent_core::Result;
+use advent_core::Solver;
+use e20d02::Entry;
+use e20d02::PasswordPolicy;
+use e20d02::Puzzle;
+use e20d02::Registry;
+use e20d02::Unit;
+use std::process;
+use std::thread;
+
+const OPTIONS: &[&str] = &[
+    "format", "policy", "unit", "stream", "report", "bench", "lines", "runs", "threads",
+];
+
+fn bench(args: &Args, entries: &[Entry], policy: &dyn PasswordPolicy, unit: Unit) -> Result<()> {
+    let lines = args.parse::<usize>("lines")?.unwrap_or(1_000_000);
+    let runs = args.parse::<usize>("runs")?.unwrap_or(5);
+    let threads = threads(args)?;
+
+    let entries = entries
+        .iter()
+        .cycle()
+        .take(lines)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let (sequential, one) = measure(runs, || e20d02::validate(&entries, policy, unit))?;
+    let (parallel, many) = measure(runs, || {
+        e20d02::validate_parallel(&entries, policy, unit, threads)
+    })?;
+
+    if sequential != parallel {
+        return Err(Error::unsolved("Parallel results differ from sequential"));
+    }
 
-#[allow(dead_code)]
-#[derive(Debug)]
-struct ParseError(ErrorType, usize);
+    let valid = parallel.iter().filter(|valid| **valid).count();
+    let speedup = one.median.as_secs_f64() / many.median.as_secs_f64();
 
-type ParseResult<T> = Result<T, ParseError>;
+    println!("Valid {}: {valid} of {lines}", policy.name());
+    println!("Sequential: {:.2?} median", one.median);
+    println!("Parallel ({threads} threads): {:.2?} median", many.median);
+    println!("Speedup: {speedup:.2}x");
 
-#[derive(Debug)]
-struct Policy {
-    target: char,
-    min: usize,
-    max: usize,
+    Ok(())
 }
 
-impl FromStr for Policy {
-    type Err = ErrorType;
+fn threads(args: &Args) -> Result<usize> {
+    let default = thread::available_parallelism().map_or(1, usize::from);
+    Ok(args.parse::<usize>("threads")?.unwrap_or(default))
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use ErrorType::PolicyError;
+fn audit(args: &Args, spec: &str) -> Result<()> {
+    let policy = Registry::default().create(spec)?;
+    let unit = args.parse::<Unit>("unit")?.unwrap_or_default();
 
-        let parts = s.split_whitespace().collect::<Vec<_>>();
+    let input = args.positional().first().map(String::as_str);
+    let source = Source::new(input, Puzzle::INPUT);
 
-        let (spec, target) = match parts[..] {
-            [spec, target] => Ok((spec, target)),
-            _ => Err(PolicyError("Invalid target divider")),
-        }?;
+    if args.flag("stream") {
+        let flags = ["report", "bench", "threads"];
 
-        let spec = spec.split('-').map(str::trim).collect::<Vec<_>>();
+        if let Some(flag) = flags.iter().find(|flag| args.flag(flag)) {
+            return Err(Error::syntax(format!(
+                "--stream cannot be combined with --{flag}"
+            )));
+        }
 
-        let (min, max) = match spec[..] {
-            [min, max] => Ok((min, max)),
-            _ => Err(PolicyError("Invalid specificer divider")),
-        }?;
+        let tally = e20d02::tally(source.open()?, policy.as_ref(), unit);
 
-        let target = target
-            .parse::<char>()
-            .map_err(|_| PolicyError("Invalid target length"))?;
+        for error in &tally.errors {
+            eprintln!("{error}");
+        }
 
-        let min = min
-            .parse::<usize>()
-            .map_err(|_| PolicyError("Invalid min specificer"))?;
+        if tally.skipped > tally.errors.len() {
+            eprintln!("... {} more errors", tally.skipped - tally.errors.len());
+        }
 
-        let max = max
-            .parse::<usize>()
-            .map_err(|_| PolicyError("Invalid max specificer"))?;
+        println!(
+            "Valid {}: {} of {}",
+            policy.name(),
+            tally.valid,
+            tally.entries
+        );
+        println!("Skipped {} malformed lines", tally.skipped);
 
-        Ok(Policy { target, min, max })
+        return Ok(());
     }
-}
 
-#[derive(Debug)]
-struct Entry {
-    policy: Policy,
-    password: String,
-}
-
-impl FromStr for Entry {
-    type Err = ErrorType;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use ErrorType::EntryError;
-
-        let parts = s.split(':').map(str::trim).collect::<Vec<_>>();
+    let text = source.read()?;
+    let entries = e20d02::parse(&text)?;
+    e20d02::verify(&entries, policy.as_ref())?;
 
-        let (policy, password) = match parts[..] {
-            [policy, password] => Ok((policy, password)),
-            _ => Err(EntryError("Invalid password divider")),
-        }?;
-
-        let policy = policy.parse::<Policy>()?;
-        let password = password.to_owned();
-
-        Ok(Entry { policy, password })
+    if args.flag("bench") {
+        return bench(args, &entries, policy.as_ref(), unit);
     }
-}
 
-fn parse_line(line: (usize, &str)) -> ParseResult<Entry> {
-    let (number, text) = line;
+    if args.flag("report") {
+        let format = args.parse::<Format>("format")?.unwrap_or_default();
+        let diagnostics = e20d02::diagnose(&entries, policy.as_ref(), unit);
 
-    text.parse().map_err(|error| ParseError(error, number))
-}
-
-fn parse() -> ParseResult<Vec<Entry>> {
-    INPUT.lines().enumerate().map(parse_line).collect()
-}
-
-fn validate_primary(entry: &Entry) -> bool {
-    let password = &entry.password;
-    let policy = &entry.policy;
-
-    let count = password.matches(policy.target).count();
-
-    policy.min <= count && count <= policy.max
-}
+        if !diagnostics.is_empty() {
+            println!("{}", e20d02::render(&diagnostics, format));
+        }
 
-fn validate_secondary(entry: &Entry) -> bool {
-    let password = &entry.password;
-    let policy = &entry.policy;
-    let target = &policy.target;
-
-    let plucked = [
-        password.chars().nth(policy.min - 1),
-        password.chars().nth(policy.max - 1),
-    ];
+        return Ok(());
+    }
 
-    let chars = plucked.iter().filter_map(|opt| *opt).collect::<Vec<char>>();
-    let matches = chars.iter().filter(|chr| *chr == target).count();
+    let valid = match args.flag("threads") {
+        true => e20d02::count_valid_parallel(&entries, policy.as_ref(), unit, threads(args)?),
+        false => e20d02::count_valid(&entries, policy.as_ref(), unit),
+    };
+    println!("Valid {}: {valid} of {}", policy.name(), entries.len());
 
-    matches == 1
+    Ok(())
 }
 
-fn main() -> ParseResult<()> {
-    let entries = parse()?;
-
-    let primary = entries.iter().filter(|e| validate_primary(e)).count();
-    println!("Primary count: {}", primary);
+fn main() {
+    let args = Args::from_env();
 
-    let secondary = entries.iter().filter(|e| validate_secondary(e)).count();
-    println!("Secondary count: {}", secondary);
+    if let Err(error) = args.allow(OPTIONS) {
+        eprintln!("{error}");
+        process::exit(1);
+    }
 
-    Ok(())
+    let audit_only = OPTIONS
+        .iter()
+        .filter(|&&option| option != "format")
+        .any(|flag| args.flag(flag));
+
+    let spec = match (args.value("policy"), audit_only) {
+        (Some(spec), _) => spec,
+        (None, true) => "count",
+        (None, false) => return advent_core::solver::run::<Puzzle>(),
+    };
+
+    if let Err(error) = audit(&args, spec) {
+        let names = Registry::default().names().collect::<Vec<_>>();
+        eprintln!("{error}");
+        eprintln!("Known policies: {}", names.join(", "));
+        process::exit(1);
+    }
 }
@@ -0,0 +1,62 @@
+use super::count_valid;
+use super::Entry;
+use super::Password;
+use super::PasswordPolicy;
+use super::Unit;
+use std::panic;
+use std::thread;
+
+pub fn validate(entries: &[Entry], policy: &dyn PasswordPolicy, unit: Unit) -> Vec<bool> {
+    entries
+        .iter()
+        .map(|entry| {
+            entry
+                .expr
+                .eval(policy, &Password::new(&entry.password, unit))
+        })
+        .collect()
+}
+
+fn chunked<T, F>(entries: &[Entry], threads: usize, work: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&[Entry]) -> T + Sync,
+{
+    let size = entries.len().div_ceil(threads.max(1)).max(1);
+    let work = &work;
+
+    thread::scope(|scope| {
+        let workers = entries
+            .chunks(size)
+            .map(|chunk| scope.spawn(move || work(chunk)))
+            .collect::<Vec<_>>();
+
+        workers
+            .into_iter()
+            .map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|error| panic::resume_unwind(error))
+            })
+            .collect()
+    })
+}
+
+pub fn validate_parallel(
+    entries: &[Entry],
+    policy: &dyn PasswordPolicy,
+    unit: Unit,
+    threads: usize,
+) -> Vec<bool> {
+    chunked(entries, threads, |chunk| validate(chunk, policy, unit)).concat()
+}
+
+pub fn count_valid_parallel(
+    entries: &[Entry],
+    policy: &dyn PasswordPolicy,
+    unit: Unit,
+    threads: usize,
+) -> usize {
+    let counts = chunked(entries, threads, |chunk| count_valid(chunk, policy, unit));
+    counts.into_iter().sum()
+}
@@ -0,0 +1,258 @@
+use advent_core::Error;
+use advent_core::Result;
+use std::fmt;
+use std::iter::Enumerate;
+use std::iter::Peekable;
+use std::str::FromStr;
+
+pub(crate) type Chars<'a> = Peekable<Enumerate<std::str::Chars<'a>>>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Class {
+    negated: bool,
+    ranges: Vec<(char, char)>,
+    clusters: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Repeat {
+    One,
+    Optional,
+    Any,
+    Many,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Atom {
+    Dot,
+    Class(Class),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    start: bool,
+    end: bool,
+    items: Vec<(Atom, Repeat)>,
+    source: String,
+}
+
+pub(crate) fn escaped(chars: &mut Chars, column: usize) -> Result<char> {
+    match chars.next() {
+        Some((_, chr)) => Ok(chr),
+        None => Err(Error::syntax("Dangling escape").with_column(column + 1)),
+    }
+}
+
+impl Class {
+    pub fn single(chr: char) -> Self {
+        Class {
+            negated: false,
+            ranges: vec![(chr, chr)],
+            clusters: vec![],
+        }
+    }
+
+    pub fn literal(text: &str) -> Self {
+        let mut chars = text.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(chr), None) => Class::single(chr),
+            _ => Class {
+                negated: false,
+                ranges: vec![],
+                clusters: vec![text.to_string()],
+            },
+        }
+    }
+
+    pub fn contains(&self, chr: char) -> bool {
+        let found = self
+            .ranges
+            .iter()
+            .any(|(lower, upper)| (*lower..=*upper).contains(&chr));
+
+        found != self.negated
+    }
+
+    pub fn matches(&self, unit: &str) -> bool {
+        let mut chars = unit.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(chr), None) => self.contains(chr),
+            _ => self.clusters.iter().any(|cluster| cluster == unit) != self.negated,
+        }
+    }
+
+    pub(crate) fn parse_bracket(chars: &mut Chars, open: usize) -> Result<Self> {
+        let unclosed = || Error::syntax("Unclosed character class").with_column(open + 1);
+
+        let negated = chars.next_if(|(_, chr)| *chr == '^').is_some();
+        let mut ranges = vec![];
+
+        loop {
+            let (column, lower) = match chars.next().ok_or_else(unclosed)? {
+                (_, ']') if !ranges.is_empty() => break,
+                (column, '\\') => (column, escaped(chars, column)?),
+                item => item,
+            };
+
+            if chars.next_if(|(_, chr)| *chr == '-').is_none() {
+                ranges.push((lower, lower));
+                continue;
+            }
+
+            let upper = match chars.next().ok_or_else(unclosed)? {
+                (column, '\\') => escaped(chars, column)?,
+                (_, ']') => {
+                    ranges.extend([(lower, lower), ('-', '-')]);
+                    break;
+                }
+                (_, chr) => chr,
+            };
+
+            if upper < lower {
+                return Err(Error::value("Inverted class range").with_column(column + 1));
+            }
+
+            ranges.push((lower, upper));
+        }
+
+        Ok(Class {
+            negated,
+            ranges,
+            clusters: vec![],
+        })
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let escape = |chr: char, special: &str| match chr {
+            chr if special.contains(chr) || chr.is_whitespace() => format!("\\{chr}"),
+            chr => chr.to_string(),
+        };
+
+        if let (false, [], [cluster]) = (self.negated, &self.ranges[..], &self.clusters[..]) {
+            return write!(f, "{cluster}");
+        }
+
+        if let (false, [(lower, upper)]) = (self.negated, &self.ranges[..]) {
+            if lower == upper {
+                return write!(f, "{}", escape(*lower, "[\\:()!&|,;"));
+            }
+        }
+
+        let negated = if self.negated { "^" } else { "" };
+
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|(lower, upper)| match lower == upper {
+                true => escape(*lower, "]\\-^"),
+                false => format!("{}-{}", escape(*lower, "]\\-^"), escape(*upper, "]\\-^")),
+            })
+            .collect::<String>();
+
+        write!(f, "[{negated}{ranges}]")
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl Atom {
+    fn matches(&self, chr: char) -> bool {
+        match self {
+            Atom::Dot => true,
+            Atom::Class(class) => class.contains(chr),
+        }
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars().enumerate().peekable();
+        let mut items = vec![];
+
+        let start = chars.next_if(|(_, chr)| *chr == '^').is_some();
+        let mut end = false;
+
+        while let Some((column, chr)) = chars.next() {
+            if end {
+                return Err(Error::syntax("Characters after end anchor").with_column(column + 1));
+            }
+
+            let atom = match chr {
+                '$' => {
+                    end = true;
+                    continue;
+                }
+                '.' => Atom::Dot,
+                '[' => Atom::Class(Class::parse_bracket(&mut chars, column)?),
+                '\\' => Atom::Class(Class::single(escaped(&mut chars, column)?)),
+                '*' | '+' | '?' => {
+                    return Err(Error::syntax("Dangling repetition").with_column(column + 1));
+                }
+                chr => Atom::Class(Class::single(chr)),
+            };
+
+            let repeat = match chars.next_if(|(_, chr)| "*+?".contains(*chr)) {
+                Some((_, '?')) => Repeat::Optional,
+                Some((_, '*')) => Repeat::Any,
+                Some((_, '+')) => Repeat::Many,
+                _ => Repeat::One,
+            };
+
+            items.push((atom, repeat));
+        }
+
+        let source = s.to_string();
+
+        Ok(Pattern {
+            start,
+            end,
+            items,
+            source,
+        })
+    }
+}
+
+impl Pattern {
+    fn match_here(&self, items: &[(Atom, Repeat)], text: &[char]) -> bool {
+        let Some(((atom, repeat), rest)) = items.split_first() else {
+            return !self.end || text.is_empty();
+        };
+
+        let first = text.first().is_some_and(|chr| atom.matches(*chr));
+
+        match repeat {
+            Repeat::One => first && self.match_here(rest, &text[1..]),
+            Repeat::Optional => {
+                first && self.match_here(rest, &text[1..]) || self.match_here(rest, text)
+            }
+            Repeat::Any | Repeat::Many => {
+                let lower = (*repeat == Repeat::Many) as usize;
+                let upper = text.iter().take_while(|chr| atom.matches(**chr)).count();
+
+                (lower..=upper)
+                    .rev()
+                    .any(|count| self.match_here(rest, &text[count..]))
+            }
+        }
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        let text = text.chars().collect::<Vec<_>>();
+
+        if self.start {
+            return self.match_here(&self.items, &text);
+        }
+
+        (0..=text.len()).any(|offset| self.match_here(&self.items, &text[offset..]))
+    }
+}
@@ -0,0 +1,224 @@
+use super::pattern::Pattern;
+use super::unit::Password;
+use super::Policy;
+use advent_core::Error;
+use advent_core::Result;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+pub trait PasswordPolicy: Send + Sync {
+    fn name(&self) -> &str;
+    fn check(&self, policy: &Policy, password: &Password) -> bool;
+
+    fn verify(&self, _: &Policy) -> Result<()> {
+        Ok(())
+    }
+
+    fn explain(&self, policy: &Policy, password: &Password) -> Option<String> {
+        match self.check(policy, password) {
+            true => None,
+            false => Some(format!("rejected by {} policy", self.name())),
+        }
+    }
+}
+
+type Factory = fn(Option<&str>) -> Result<Box<dyn PasswordPolicy>>;
+
+pub struct Registry(BTreeMap<&'static str, Factory>);
+
+pub struct CountRange;
+
+pub struct ExactlyOne;
+
+pub struct AllPositions;
+
+pub struct Forbidden(Vec<char>);
+
+pub struct Matches(Pattern);
+
+impl PasswordPolicy for CountRange {
+    fn name(&self) -> &str {
+        "count"
+    }
+
+    fn check(&self, policy: &Policy, password: &Password) -> bool {
+        let count = password.count(&policy.target);
+
+        policy.min <= count && count <= policy.max
+    }
+
+    fn explain(&self, policy: &Policy, password: &Password) -> Option<String> {
+        let count = password.count(&policy.target);
+
+        match self.check(policy, password) {
+            true => None,
+            false => Some(format!(
+                "counted {count} '{}' but allowed {}-{}",
+                policy.target, policy.min, policy.max
+            )),
+        }
+    }
+}
+
+fn describe(policy: &Policy, password: &Password) -> Vec<String> {
+    let length = password.len();
+    let unit = password.unit;
+
+    [policy.min, policy.max]
+        .iter()
+        .map(|position| match password.at(*position) {
+            Some(found) => format!("position {position} is '{found}'"),
+            None => format!("position {position} out of range for a {length}-{unit} password"),
+        })
+        .collect()
+}
+
+fn positional(policy: &Policy) -> Result<()> {
+    match policy.min {
+        0 => Err(Error::value("Positions start at 1")),
+        _ => Ok(()),
+    }
+}
+
+fn pluck<'a>(policy: &Policy, password: &Password<'a>) -> [Option<Cow<'a, str>>; 2] {
+    [password.at(policy.min), password.at(policy.max)]
+}
+
+impl PasswordPolicy for ExactlyOne {
+    fn name(&self) -> &str {
+        "position"
+    }
+
+    fn verify(&self, policy: &Policy) -> Result<()> {
+        positional(policy)
+    }
+
+    fn check(&self, policy: &Policy, password: &Password) -> bool {
+        let plucked = pluck(policy, password);
+        let matches = plucked
+            .iter()
+            .flatten()
+            .filter(|found| policy.target.matches(found));
+
+        matches.count() == 1
+    }
+
+    fn explain(&self, policy: &Policy, password: &Password) -> Option<String> {
+        match self.check(policy, password) {
+            true => None,
+            false => Some(format!(
+                "need exactly one '{}': {}",
+                policy.target,
+                describe(policy, password).join(", ")
+            )),
+        }
+    }
+}
+
+impl PasswordPolicy for AllPositions {
+    fn name(&self) -> &str {
+        "positions"
+    }
+
+    fn verify(&self, policy: &Policy) -> Result<()> {
+        positional(policy)
+    }
+
+    fn check(&self, policy: &Policy, password: &Password) -> bool {
+        let plucked = pluck(policy, password);
+
+        plucked.iter().all(|found| {
+            found
+                .as_ref()
+                .is_some_and(|found| policy.target.matches(found))
+        })
+    }
+
+    fn explain(&self, policy: &Policy, password: &Password) -> Option<String> {
+        match self.check(policy, password) {
+            true => None,
+            false => Some(format!(
+                "need '{}' at both: {}",
+                policy.target,
+                describe(policy, password).join(", ")
+            )),
+        }
+    }
+}
+
+impl PasswordPolicy for Forbidden {
+    fn name(&self) -> &str {
+        "forbidden"
+    }
+
+    fn check(&self, _: &Policy, password: &Password) -> bool {
+        !password.text.contains(&self.0[..])
+    }
+
+    fn explain(&self, _: &Policy, password: &Password) -> Option<String> {
+        let found = password.text.chars().find(|chr| self.0.contains(chr))?;
+
+        Some(format!("contains forbidden '{found}'"))
+    }
+}
+
+impl PasswordPolicy for Matches {
+    fn name(&self) -> &str {
+        "pattern"
+    }
+
+    fn check(&self, _: &Policy, password: &Password) -> bool {
+        self.0.is_match(password.text)
+    }
+
+    fn explain(&self, policy: &Policy, password: &Password) -> Option<String> {
+        match self.check(policy, password) {
+            true => None,
+            false => Some(format!("does not match pattern {}", self.0)),
+        }
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        let mut registry = Registry(BTreeMap::new());
+
+        registry.register("count", |_| Ok(Box::new(CountRange)));
+        registry.register("position", |_| Ok(Box::new(ExactlyOne)));
+        registry.register("positions", |_| Ok(Box::new(AllPositions)));
+
+        registry.register("forbidden", |arg| {
+            let chars = arg.ok_or(Error::missing("Missing forbidden characters"))?;
+            Ok(Box::new(Forbidden(chars.chars().collect())))
+        });
+
+        registry.register("pattern", |arg| {
+            let pattern = arg.ok_or(Error::missing("Missing pattern"))?;
+            Ok(Box::new(Matches(pattern.parse()?)))
+        });
+
+        registry
+    }
+}
+
+impl Registry {
+    pub fn register(&mut self, name: &'static str, factory: Factory) {
+        self.0.insert(name, factory);
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.0.keys().copied()
+    }
+
+    pub fn create(&self, spec: &str) -> Result<Box<dyn PasswordPolicy>> {
+        let (name, arg) = match spec.split_once(':') {
+            Some((name, arg)) => (name, Some(arg)),
+            None => (spec, None),
+        };
+
+        match self.0.get(name) {
+            Some(factory) => factory(arg),
+            None => Err(Error::value("Unknown password policy").with_text(name)),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use super::Entry;
+use super::Password;
+use super::PasswordPolicy;
+use super::Unit;
+use advent_core::output::escape_json;
+use advent_core::output::escape_tsv;
+use advent_core::output::Format;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub password: String,
+    pub reason: String,
+}
+
+pub fn diagnose(entries: &[Entry], policy: &dyn PasswordPolicy, unit: Unit) -> Vec<Diagnostic> {
+    entries
+        .iter()
+        .enumerate()
+        .filter_map(|(index, entry)| {
+            let password = Password::new(&entry.password, unit);
+            let reason = entry.expr.explain(policy, &password)?;
+
+            Some(Diagnostic {
+                line: index + 1,
+                password: entry.password.clone(),
+                reason,
+            })
+        })
+        .collect()
+}
+
+pub fn render(diagnostics: &[Diagnostic], format: Format) -> String {
+    let width = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.password.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let lines = diagnostics.iter().map(|diagnostic| {
+        let Diagnostic {
+            line,
+            password,
+            reason,
+        } = diagnostic;
+
+        match format {
+            Format::Text => format!("{line:>5}  {password:<width$}  {reason}"),
+            Format::Tsv => format!("{line}\t{}\t{}", escape_tsv(password), escape_tsv(reason)),
+            Format::Json => format!(
+                "{{\"line\":{line},\"password\":{},\"reason\":{}}}",
+                escape_json(password),
+                escape_json(reason),
+            ),
+        }
+    });
+
+    let header = match format {
+        Format::Tsv => Some("line\tpassword\treason".to_string()),
+        _ => None,
+    };
+
+    header
+        .into_iter()
+        .chain(lines)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,124 @@
+use super::parse_line;
+use super::Entry;
+use super::Password;
+use super::PasswordPolicy;
+use super::Unit;
+use advent_core::Error;
+use advent_core::ErrorKind;
+use advent_core::Result;
+use std::io::BufRead;
+use std::str;
+
+pub const KEPT_ERRORS: usize = 100;
+
+pub struct Entries<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    line: usize,
+    done: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub entries: usize,
+    pub valid: usize,
+    pub skipped: usize,
+    pub errors: Vec<Error>,
+}
+
+impl<R: BufRead> Entries<R> {
+    pub fn new(reader: R) -> Self {
+        Entries {
+            reader,
+            buffer: Vec::new(),
+            line: 0,
+            done: false,
+        }
+    }
+
+    fn decode(&self) -> Result<&str> {
+        let mut bytes = &self.buffer[..];
+
+        if let [rest @ .., b'\n'] = bytes {
+            bytes = rest;
+        }
+
+        if let [rest @ .., b'\r'] = bytes {
+            bytes = rest;
+        }
+
+        str::from_utf8(bytes).map_err(|error| {
+            let valid = str::from_utf8(&bytes[..error.valid_up_to()]).unwrap_or_default();
+            Error::value("Invalid UTF-8").with_column(valid.chars().count() + 1)
+        })
+    }
+}
+
+impl<R: BufRead> Iterator for Entries<R> {
+    type Item = Result<Entry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        self.buffer.clear();
+
+        match self.reader.read_until(b'\n', &mut self.buffer) {
+            Ok(0) => {
+                self.done = true;
+                return None;
+            }
+            Err(error) => {
+                self.done = true;
+                let error = Error::new(ErrorKind::Input, error.to_string());
+                return Some(Err(error.with_line(self.line + 1)));
+            }
+            Ok(_) => self.line += 1,
+        }
+
+        let index = self.line - 1;
+
+        Some(
+            self.decode()
+                .map_err(|error| error.with_line(self.line))
+                .and_then(|text| parse_line((index, text))),
+        )
+    }
+}
+
+impl Tally {
+    fn skip(&mut self, error: Error) {
+        self.skipped += 1;
+
+        if self.errors.len() < KEPT_ERRORS {
+            self.errors.push(error);
+        }
+    }
+}
+
+pub fn tally<R: BufRead>(reader: R, policy: &dyn PasswordPolicy, unit: Unit) -> Tally {
+    let mut tally = Tally::default();
+
+    for (index, entry) in Entries::new(reader).enumerate() {
+        let verified = |entry: Entry| match entry.expr.verify(policy) {
+            Ok(()) => Ok(entry),
+            Err(error) => Err(error.with_line(index + 1)),
+        };
+
+        let entry = match entry.and_then(verified) {
+            Ok(entry) => entry,
+            Err(error) => {
+                tally.skip(error);
+                continue;
+            }
+        };
+
+        let password = Password::new(&entry.password, unit);
+
+        tally.entries += 1;
+        tally.valid += entry.expr.eval(policy, &password) as usize;
+    }
+
+    tally
+}
@@ -0,0 +1,274 @@
+use crate::count_valid;
+use crate::count_valid_parallel;
+use crate::diagnose;
+use crate::parse;
+use crate::pattern::Pattern;
+use crate::tally;
+use crate::validate;
+use crate::validate_parallel;
+use crate::verify;
+use crate::AllPositions;
+use crate::Class;
+use crate::CountRange;
+use crate::Entries;
+use crate::Entry;
+use crate::ExactlyOne;
+use crate::Expr;
+use crate::Password;
+use crate::Policy;
+use crate::Registry;
+use crate::Unit;
+use crate::KEPT_ERRORS;
+
+const SAMPLE: &str = "1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc";
+
+fn valid(spec: &str, input: &str) -> usize {
+    valid_in(spec, Unit::Char, input)
+}
+
+fn valid_in(spec: &str, unit: Unit, input: &str) -> usize {
+    let policy = Registry::default().create(spec).unwrap();
+    let entries = parse(input).unwrap();
+
+    count_valid(&entries, policy.as_ref(), unit)
+}
+
+#[test]
+fn test_sample() {
+    assert_eq!(valid("count", SAMPLE), 2);
+    assert_eq!(valid("position", SAMPLE), 1);
+    assert_eq!(valid("positions", SAMPLE), 1);
+}
+
+#[test]
+fn test_configured() {
+    assert_eq!(valid("forbidden:xyz", SAMPLE), 3);
+    assert_eq!(valid("forbidden:e", SAMPLE), 1);
+    assert_eq!(valid("pattern:^c+$", SAMPLE), 1);
+    assert_eq!(valid("pattern:[a-c]d", SAMPLE), 2);
+}
+
+#[test]
+fn test_unknown() {
+    let registry = Registry::default();
+
+    assert!(registry.create("length").is_err());
+    assert!(registry.create("forbidden").is_err());
+    assert!(registry.create("pattern:[a-").is_err());
+}
+
+#[test]
+fn test_pattern() {
+    let matches = |pattern: &str, text: &str| pattern.parse::<Pattern>().unwrap().is_match(text);
+
+    assert!(matches("a.c", "xxabcxx"));
+    assert!(matches("^ab?c$", "ac"));
+    assert!(matches("^[^0-9]+[0-9]*$", "pass123"));
+    assert!(!matches("^[^0-9]+[0-9]*$", "12pass"));
+    assert!(matches("\\.", "a.b"));
+    assert!(!matches("^a*b$", "aaac"));
+}
+
+fn entry(line: &str) -> Entry {
+    line.parse().unwrap()
+}
+
+fn column(line: &str) -> Option<usize> {
+    line.parse::<Entry>().unwrap_err().column()
+}
+
+#[test]
+fn test_grammar() {
+    let rule = |min, max, target: char| {
+        Expr::Rule(Policy {
+            target: Class::single(target),
+            min,
+            max,
+        })
+    };
+
+    let parsed = entry("1-3 a, not 2 b or len 4-8: secret");
+
+    let expect = Expr::Or(vec![
+        Expr::And(vec![rule(1, 3, 'a'), Expr::Not(Box::new(rule(2, 2, 'b')))]),
+        Expr::Length(4, 8),
+    ]);
+
+    assert_eq!(parsed.expr, expect);
+    assert_eq!(parsed.password, "secret");
+}
+
+#[test]
+fn test_combined() {
+    let valid = |line: &str| {
+        let entry = entry(line);
+        entry
+            .expr
+            .eval(&CountRange, &Password::new(&entry.password, Unit::Char))
+    };
+
+    assert!(valid("1-3 a, 2-9 [0-9]; len 8-64: abc12345"));
+    assert!(!valid("1-3 a, 2-9 [0-9]; len 8-64: abc1"));
+    assert!(!valid("1-3 a, 2-9 [0-9]; len 8-64: xyz12345"));
+    assert!(valid("(1-3 a | 1 b) & !1-9 [^a-z0-9]: b123"));
+    assert!(!valid("(1-3 a | 1 b) & !1-9 [^a-z0-9]: b12#"));
+    assert!(valid("0 [:]: no colon"));
+}
+
+#[test]
+fn test_positions() {
+    assert_eq!(column("1-3 a b: abc"), Some(7));
+    assert_eq!(column("1-x a: abc"), Some(3));
+    assert_eq!(column("1-3 [ab: abc"), Some(5));
+    assert_eq!(column("1-3 [z-a]: abc"), Some(6));
+    assert_eq!(column("(1-3 a: abc"), Some(7));
+    assert_eq!(column("1-3 a, : abc"), Some(8));
+    assert_eq!(column("1-3 a abc"), Some(7));
+    assert_eq!(column("a | 3-1 a: abc"), Some(1));
+    assert_eq!(column("1 a | 3-1 a: abc"), Some(7));
+    assert_eq!(column("len 9-2: abc"), Some(5));
+
+    let entries = parse("0-3 a: abc\n1-3 b: cde\n").unwrap();
+    assert!(verify(&entries, &CountRange).is_ok());
+    assert_eq!(verify(&entries, &ExactlyOne).unwrap_err().line(), Some(1));
+    assert!(verify(&entries, &AllPositions).is_err());
+
+    let tally = tally("0 a: abc\n1-2 a: abc\n".as_bytes(), &ExactlyOne, Unit::Char);
+    assert_eq!((tally.entries, tally.valid, tally.skipped), (1, 1, 1));
+}
+
+#[test]
+fn test_units() {
+    let decomposed = "1-2 e\u{301}: e\u{301}x";
+    let composed = "2-3 \u{e9}: a\u{e9}\u{e9}";
+
+    assert_eq!(valid_in("position", Unit::Char, decomposed), 0);
+    assert_eq!(valid_in("position", Unit::Grapheme, decomposed), 1);
+    assert_eq!(valid_in("count", Unit::Grapheme, "len 2: e\u{301}x"), 1);
+    assert_eq!(valid_in("count", Unit::Char, "len 2: e\u{301}x"), 0);
+
+    assert_eq!(valid_in("positions", Unit::Char, composed), 1);
+    assert_eq!(valid_in("positions", Unit::Byte, composed), 0);
+    assert_eq!(valid_in("positions", Unit::Byte, "2-3 b: abbé"), 1);
+    assert_eq!(valid_in("count", Unit::Byte, "len 5: abbé"), 1);
+
+    let password = Password::new("a\u{e9}", Unit::Byte);
+    assert_eq!(password.at(2).as_deref(), Some("\\xc3"));
+    assert_eq!(password.at(0), None);
+    assert_eq!(password.at(4), None);
+}
+
+fn reasons(spec: &str, input: &str) -> Vec<(usize, String)> {
+    let policy = Registry::default().create(spec).unwrap();
+    let entries = parse(input).unwrap();
+
+    diagnose(&entries, policy.as_ref(), Unit::Char)
+        .into_iter()
+        .map(|diagnostic| (diagnostic.line, diagnostic.reason))
+        .collect()
+}
+
+#[test]
+fn test_diagnose() {
+    let count = reasons("count", SAMPLE);
+    assert_eq!(count, [(2, "counted 0 'b' but allowed 1-3".to_string())]);
+
+    let position = reasons("position", SAMPLE);
+    assert_eq!(position.len(), 2);
+    assert_eq!(position[0].0, 2);
+    assert_eq!(
+        position[1].1,
+        "need exactly one 'c': position 2 is 'c', position 9 is 'c'"
+    );
+
+    let short = reasons("positions", "1-9 a: abc");
+    assert_eq!(
+        short[0].1,
+        "need 'a' at both: position 1 is 'a', position 9 out of range for a 3-char password"
+    );
+
+    assert_eq!(
+        reasons("forbidden:e", SAMPLE)[0].1,
+        "contains forbidden 'e'"
+    );
+    assert_eq!(
+        reasons("pattern:^c+$", SAMPLE)[0].1,
+        "does not match pattern ^c+$"
+    );
+}
+
+#[test]
+fn test_explain() {
+    let input = "len 6-8 and (1-3 [0-9] or not 1-1 a): abcde";
+    assert_eq!(
+        reasons("count", input)[0].1,
+        "length 5 but allowed 6-8; none of: counted 0 '[0-9]' but allowed 1-3 | 1-1 a holds but is negated"
+    );
+
+    let entries = parse(input).unwrap();
+    assert_eq!(
+        entries[0].expr.to_string(),
+        "len 6-8 and (1-3 [0-9] or not 1-1 a)"
+    );
+}
+
+#[test]
+fn test_stream() {
+    let input: &[u8] =
+        b"1-3 a: abcde\r\n1-3: b\n2-9 c: ccccccccc\n1-3 \xff: x\n0-1 a: a\n1-3 b: cdefg";
+    let policy = Registry::default().create("count").unwrap();
+    let result = tally(input, policy.as_ref(), Unit::Char);
+
+    assert_eq!(result.entries, 4);
+    assert_eq!(result.valid, 3);
+
+    let lines = result.errors.iter().map(|error| error.line());
+    assert_eq!(lines.collect::<Vec<_>>(), [Some(2), Some(4)]);
+    assert_eq!(result.errors[1].message(), "Invalid UTF-8");
+    assert_eq!(result.errors[1].column(), Some(5));
+
+    let streamed = Entries::new(SAMPLE.as_bytes()).collect::<Result<Vec<_>, _>>();
+    assert_eq!(streamed.unwrap(), parse(SAMPLE).unwrap());
+
+    let garbage = "1-3 a b\n".repeat(KEPT_ERRORS * 3);
+    let result = tally(garbage.as_bytes(), policy.as_ref(), Unit::Char);
+    assert_eq!(
+        (result.skipped, result.errors.len()),
+        (KEPT_ERRORS * 3, KEPT_ERRORS)
+    );
+    assert_eq!(result.errors.last().unwrap().line(), Some(KEPT_ERRORS));
+}
+
+#[test]
+fn test_parallel() {
+    let lines = (0..200).map(|index| {
+        let min = index % 5 + 1;
+        let max = min + index % 3;
+        let password = (0..index % 11).map(|offset| ['a', 'b'][(index + offset) % 2]);
+
+        format!("{min}-{max} a: {}", password.collect::<String>())
+    });
+
+    let input = lines.collect::<Vec<_>>().join("\n");
+    let entries = parse(&input).unwrap();
+
+    for spec in ["count", "position", "positions"] {
+        let policy = Registry::default().create(spec).unwrap();
+        let policy = policy.as_ref();
+        let expected = validate(&entries, policy, Unit::Char);
+        let valid = expected.iter().filter(|valid| **valid).count();
+
+        for threads in [0, 1, 3, 8, 300] {
+            let parallel = validate_parallel(&entries, policy, Unit::Char, threads);
+            let count = count_valid_parallel(&entries, policy, Unit::Char, threads);
+
+            assert_eq!(parallel, expected);
+            assert_eq!(count, valid);
+        }
+    }
+
+    let policy = Registry::default().create("count").unwrap();
+    assert!(validate_parallel(&[], policy.as_ref(), Unit::Char, 4).is_empty());
+}
@@ -0,0 +1,90 @@
+use super::pattern::Class;
+use advent_core::Error;
+use advent_core::Result;
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Unit {
+    #[default]
+    Char,
+    Grapheme,
+    Byte,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Password<'a> {
+    pub text: &'a str,
+    pub unit: Unit,
+}
+
+impl FromStr for Unit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "char" => Ok(Unit::Char),
+            "grapheme" => Ok(Unit::Grapheme),
+            "byte" => Ok(Unit::Byte),
+            _ => Err(Error::value("Unknown position unit").with_text(s)),
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unit::Char => write!(f, "char"),
+            Unit::Grapheme => write!(f, "grapheme"),
+            Unit::Byte => write!(f, "byte"),
+        }
+    }
+}
+
+fn byte(text: &str, index: usize) -> Cow<'_, str> {
+    match text.as_bytes()[index] {
+        value if value.is_ascii() => Cow::Borrowed(&text[index..=index]),
+        value => Cow::Owned(format!("\\x{value:02x}")),
+    }
+}
+
+impl<'a> Password<'a> {
+    pub fn new(text: &'a str, unit: Unit) -> Self {
+        Password { text, unit }
+    }
+
+    pub fn units(&self) -> Box<dyn Iterator<Item = Cow<'a, str>> + 'a> {
+        let text = self.text;
+
+        match self.unit {
+            Unit::Char => Box::new(
+                text.char_indices()
+                    .map(move |(index, chr)| Cow::Borrowed(&text[index..index + chr.len_utf8()])),
+            ),
+            Unit::Grapheme => Box::new(text.graphemes(true).map(Cow::Borrowed)),
+            Unit::Byte => Box::new((0..text.len()).map(move |index| byte(text, index))),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self.unit {
+            Unit::Char => self.text.chars().count(),
+            Unit::Grapheme => self.text.graphemes(true).count(),
+            Unit::Byte => self.text.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn at(&self, position: usize) -> Option<Cow<'a, str>> {
+        self.units().nth(position.checked_sub(1)?)
+    }
+
+    pub fn count(&self, target: &Class) -> usize {
+        self.units().filter(|unit| target.matches(unit)).count()
+    }
+}
@@ -0,0 +1,116 @@
+use super::Tile;
+use advent_core::Error;
+use advent_core::Result;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Kind {
+    pub name: String,
+    pub weight: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Legend {
+    symbols: Vec<(char, Kind)>,
+}
+
+impl Kind {
+    pub fn is_tree(&self) -> bool {
+        self.name == "tree"
+    }
+}
+
+impl Default for Legend {
+    fn default() -> Self {
+        let legend = Legend::empty().with('.', "open", 0);
+        legend
+            .and_then(|legend| legend.with('#', "tree", 1))
+            .expect("valid default legend")
+    }
+}
+
+impl Legend {
+    pub fn empty() -> Self {
+        Legend { symbols: vec![] }
+    }
+
+    pub fn with(mut self, symbol: char, name: &str, weight: u64) -> Result<Self> {
+        let kind = Kind {
+            name: name.to_string(),
+            weight,
+        };
+
+        let full = self.symbols.len() > u8::MAX as usize;
+
+        match self.symbols.iter_mut().find(|(known, _)| *known == symbol) {
+            Some((_, known)) => *known = kind,
+            None if full => {
+                return Err(Error::value("Legend has more than 256 symbols").with_text(symbol));
+            }
+            None => self.symbols.push((symbol, kind)),
+        }
+
+        Ok(self)
+    }
+
+    pub fn kinds(&self) -> impl Iterator<Item = (char, &Kind)> + '_ {
+        self.symbols.iter().map(|(symbol, kind)| (*symbol, kind))
+    }
+
+    pub(crate) fn kind(&self, tile: Tile) -> &Kind {
+        &self.symbols[tile.0 as usize].1
+    }
+
+    pub(crate) fn trees(&self) -> Result<Vec<bool>> {
+        let trees = self.symbols.iter().map(|(_, kind)| kind.is_tree());
+        let trees = trees.collect::<Vec<_>>();
+
+        match trees.contains(&true) {
+            true => Ok(trees),
+            false => Err(Error::missing("Legend has no tree kind")),
+        }
+    }
+
+    pub(crate) fn tile(&self, symbol: char) -> Result<Tile> {
+        let index = self.symbols.iter().position(|(known, _)| *known == symbol);
+        let invalid = || Error::value("Invalid character").with_text(symbol);
+
+        Ok(Tile(index.ok_or_else(invalid)? as u8))
+    }
+}
+
+fn entry(text: &str) -> Result<(char, String, u64)> {
+    let invalid = || Error::syntax("Expected symbol=name:weight").with_text(text);
+
+    let mut chars = text.chars();
+    let symbol = chars.next().ok_or_else(invalid)?;
+    let rest = chars.as_str().strip_prefix('=').ok_or_else(invalid)?;
+
+    let (name, weight) = match rest.split_once(':') {
+        Some((name, weight)) => (name, weight.parse()?),
+        None => (rest, 0),
+    };
+
+    match name.is_empty() {
+        true => Err(invalid()),
+        false => Ok((symbol, name.to_string(), weight)),
+    }
+}
+
+impl FromStr for Legend {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut legend = Legend::empty();
+
+        for text in s.split_whitespace() {
+            let (symbol, name, weight) = entry(text).map_err(|error| error.with_text(text))?;
+            legend = legend.with(symbol, &name, weight)?;
+        }
+
+        match legend.symbols.is_empty() {
+            true => Err(Error::missing("Empty legend")),
+            false => Ok(legend),
+        }
+    }
+}
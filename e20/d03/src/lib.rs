@@ -0,0 +1,219 @@
+use advent_core::grid::Grid;
+use advent_core::grid::Wrap;
+use advent_core::Error;
+use advent_core::Result;
+use advent_core::Solver;
+use std::fmt;
+use std::str::FromStr;
+
+pub use self::legend::Kind;
+pub use self::legend::Legend;
+pub use self::packed::synthetic;
+pub use self::packed::Packed;
+pub use self::render::*;
+pub use self::search::Ranking;
+
+mod legend;
+mod packed;
+mod render;
+mod search;
+
+#[cfg(test)]
+mod tests;
+
+const INPUT: &str = "input/2020/03.txt";
+const RUNS: [(isize, isize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Tile(u8);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Slope {
+    pub right: isize,
+    pub down: isize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Board {
+    tiles: Grid<Tile>,
+    legend: Legend,
+}
+
+fn gcd(a: isize, b: isize) -> usize {
+    match b {
+        0 => a.unsigned_abs(),
+        _ => gcd(b, a.wrapping_rem(b)),
+    }
+}
+
+impl Slope {
+    pub fn new(right: isize, down: isize) -> Result<Self> {
+        match down {
+            0 => Err(Error::value("Slope must move vertically")),
+            _ => Ok(Slope { right, down }),
+        }
+    }
+
+    pub fn ratio(right: isize, down: isize) -> Result<Self> {
+        let divisor = gcd(right, down).max(1) as i128;
+        let reduce = |value: isize| (value as i128 / divisor) as isize;
+
+        Slope::new(reduce(right), reduce(down))
+    }
+}
+
+impl FromStr for Slope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parse = |text: &str| text.trim().parse::<isize>().map_err(Error::from);
+
+        let result = match (s.split_once(','), s.split_once('/')) {
+            (Some((right, down)), None) => Slope::new(parse(right)?, parse(down)?),
+            (None, Some((right, down))) => Slope::ratio(parse(right)?, parse(down)?),
+            _ => Err(Error::syntax("Expected right,down or right/down")),
+        };
+
+        result.map_err(|error| error.with_text(s))
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.right, self.down)
+    }
+}
+
+fn check_start(start: (usize, usize), height: usize, width: usize) -> Result<()> {
+    let (row, column) = start;
+
+    match row < height && column < width {
+        true => Ok(()),
+        false => {
+            let position = format!("{row},{column}");
+            Err(Error::value("Start outside the board").with_text(position))
+        }
+    }
+}
+
+impl FromStr for Board {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Board::parse_with(s, Legend::default())
+    }
+}
+
+impl Board {
+    pub fn parse_with(input: &str, legend: Legend) -> Result<Self> {
+        let tiles = Grid::parse(input, |symbol| legend.tile(symbol))?;
+
+        if tiles.height() == 0 {
+            return Err(Error::missing("Empty map"));
+        }
+
+        let tiles = tiles.with_wrap(Wrap::Horizontal);
+
+        Ok(Board { tiles, legend })
+    }
+
+    pub fn legend(&self) -> &Legend {
+        &self.legend
+    }
+
+    fn get_tile(&self, row: usize, column: usize) -> Result<Tile> {
+        let tile = self.tiles.get(row, column).copied();
+
+        tile.ok_or_else(|| {
+            let position = format!("{row},{column}");
+            Error::value("Invalid board position").with_text(position)
+        })
+    }
+
+    fn check_start(&self, start: (usize, usize)) -> Result<()> {
+        check_start(start, self.tiles.height(), self.tiles.width())
+    }
+
+    fn trail(&self, slope: Slope, start: (usize, usize)) -> impl Iterator<Item = (isize, isize)> {
+        let row = start.0 as isize;
+        let column = start.1 as isize;
+        let rows = match slope.down {
+            0 => 0..0,
+            _ => 0..self.tiles.height() as isize,
+        };
+
+        (1..)
+            .map_while(move |step: isize| {
+                let row = step.checked_mul(slope.down)?.checked_add(row)?;
+                let column = step.checked_mul(slope.right)?.checked_add(column)?;
+
+                Some((row, column))
+            })
+            .take_while(move |(row, _)| rows.contains(row))
+    }
+
+    pub fn path(
+        &self,
+        slope: Slope,
+        start: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.trail(slope, start)
+            .map_while(move |(row, column)| self.tiles.locate(row, column))
+    }
+
+    fn fold<T>(
+        &self,
+        slope: Slope,
+        start: (usize, usize),
+        init: T,
+        mut step: impl FnMut(T, Tile) -> T,
+    ) -> Result<T> {
+        self.check_start(start)?;
+
+        let mut result = init;
+
+        for (row, column) in self.path(slope, start) {
+            result = step(result, self.get_tile(row, column)?);
+        }
+
+        Ok(result)
+    }
+
+    pub fn count_trees(&self, slope: Slope, start: (usize, usize)) -> Result<usize> {
+        let trees = self.legend.trees()?;
+        self.fold(slope, start, 0, |count, tile| {
+            count + trees[tile.0 as usize] as usize
+        })
+    }
+
+    pub fn cost(&self, slope: Slope, start: (usize, usize)) -> Result<u64> {
+        self.fold(slope, start, 0, |cost, tile| {
+            cost.saturating_add(self.legend.kind(tile).weight)
+        })
+    }
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const EVENT: u16 = 2020;
+    const DAY: u8 = 3;
+    const INPUT: &'static str = INPUT;
+
+    type Input = Board;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(board: &Self::Input) -> Result<Self::Answer> {
+        board.count_trees(Slope::new(3, 1)?, (0, 0))
+    }
+
+    fn part_two(board: &Self::Input) -> Result<Self::Answer> {
+        RUNS.iter()
+            .map(|run| board.count_trees(Slope::new(run.0, run.1)?, (0, 0)))
+            .product()
+    }
+}
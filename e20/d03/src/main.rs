@@ -1,97 +1,154 @@
-use std::convert::TryFrom;
-use std::str::FromStr;
+use advent_core::args::Args;
+use advent_core::bench::measure;
+use advent_core::input::Source;
+use advent_core::Error;
+use advent_core::ErrorKind;
+use advent_core::Result;
+use advent_core::Solver;
+use e20d03::synthetic;
+use e20d03::Board;
+use e20d03::Legend;
+use e20d03::Packed;
+use e20d03::Puzzle;
+use e20d03::Slope;
+use std::io;
+use std::io::Write;
+use std::process;
+
+const OPTIONS: &[&str] = &[
+    "format", "slope", "start", "search", "render", "repeat", "scale", "legend", "cost", "bench",
+    "runs",
+];
+
+fn pair(args: &Args, name: &str) -> Result<Option<(usize, usize)>> {
+    let Some(text) = args.value(name) else {
+        return Ok(None);
+    };
+
+    let invalid = || Error::syntax("Expected a pair like 3,1").with_text(text);
+    let (first, second) = text.split_once(',').ok_or_else(invalid)?;
+
+    Ok(Some((first.trim().parse()?, second.trim().parse()?)))
+}
 
-const INPUT: &str = include_str!("input.txt");
-const RUNS: [(usize, usize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+fn search(board: &Board, (right, down): (usize, usize)) -> Result<()> {
+    let ranking = board.search(right, down)?;
 
-#[derive(Debug)]
-enum ParserError {
-    InvalidCharacter,
-}
+    if let Some((slope, trees)) = ranking.min() {
+        println!("Fewest trees: {trees} at {slope}");
+    }
 
-type ParserResult<T> = Result<T, ParserError>;
+    if let Some((slope, trees)) = ranking.max() {
+        println!("Most trees: {trees} at {slope}");
+    }
+
+    for (trees, slopes) in ranking.distribution() {
+        println!("{trees:>6} trees: {slopes} slopes");
+    }
 
-#[derive(Clone, Debug)]
-enum Tile {
-    Empty,
-    Tree,
+    Ok(())
 }
 
-impl TryFrom<char> for Tile {
-    type Error = ParserError;
+fn bench(args: &Args) -> Result<()> {
+    let runs = args.parse::<usize>("runs")?.unwrap_or(5);
+    let boards = [("tall", 31, 2_000_000), ("wide", 10_000, 1_000)];
 
-    fn try_from(value: char) -> ParserResult<Self> {
-        use ParserError::InvalidCharacter;
+    let slopes = (1..=2)
+        .flat_map(|down| (0..=7).map(move |right| Slope::new(right, down)))
+        .collect::<Result<Vec<_>>>()?;
 
-        match value {
-            '.' => Ok(Tile::Empty),
-            '#' => Ok(Tile::Tree),
-            _ => Err(InvalidCharacter),
+    for (name, width, height) in boards {
+        let board = synthetic(width, height, 0x5eed).parse::<Board>()?;
+        let packed = Packed::new(&board)?;
+
+        let (expected, plain) = measure(runs, || {
+            slopes
+                .iter()
+                .map(|slope| board.count_trees(*slope, (0, 0)))
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        let (counts, fast) = measure(runs, || packed.count_many(&slopes, (0, 0)))?;
+
+        if counts? != expected? {
+            return Err(Error::unsolved("Packed counts differ from the board"));
         }
+
+        let speedup = plain.median.as_secs_f64() / fast.median.as_secs_f64();
+
+        println!("{name} {width}x{height}, {} slopes", slopes.len());
+        println!("  Board: {:.2?} median", plain.median);
+        println!("  Packed: {:.2?} median", fast.median);
+        println!("  Speedup: {speedup:.2}x");
     }
-}
 
-#[derive(Clone, Debug)]
-struct Board {
-    tiles: Vec<Vec<Tile>>,
+    Ok(())
 }
 
-impl FromStr for Board {
-    type Err = ParserError;
+fn audit(args: &Args) -> Result<()> {
+    if args.flag("bench") {
+        return bench(args);
+    }
 
-    fn from_str(s: &str) -> ParserResult<Self> {
-        let tiles = s
-            .lines()
-            .map(|line| line.chars().map(Tile::try_from).collect())
-            .collect::<ParserResult<Vec<Vec<Tile>>>>()?;
+    let input = args.positional().first().map(String::as_str);
+    let text = Source::new(input, Puzzle::INPUT).read()?;
+    let legend = args.parse::<Legend>("legend")?.unwrap_or_default();
+    let board = Board::parse_with(&text, legend)?;
 
-        Ok(Board { tiles })
+    if let Some(bounds) = pair(args, "search")? {
+        return search(&board, bounds);
     }
-}
 
-impl Board {
-    fn get_tile(&self, row: usize, column: usize) -> Option<Tile> {
-        let row = self.tiles.get(row)?;
-        let tile = row.get(column % row.len())?;
+    let slope = args.parse::<Slope>("slope")?.unwrap_or(Slope::new(3, 1)?);
+    let start = pair(args, "start")?.unwrap_or((0, 0));
 
-        Some(tile.clone())
+    if args.flag("cost") {
+        println!("Cost at {slope}: {}", board.cost(slope, start)?);
+        return Ok(());
     }
 
-    fn count_trees(&self, col_speed: usize, row_speed: usize) -> usize {
-        let row_count = self.tiles.len();
-
-        let mut trees = 0;
-        let mut current_row = row_speed;
-        let mut current_col = col_speed;
+    let render = match (args.flag("render"), args.value("render")) {
+        (_, Some(render)) => render,
+        (true, None) => return Err(Error::syntax("Expected --render=text|ppm")),
+        (false, None) => {
+            println!("Trees at {slope}: {}", board.count_trees(slope, start)?);
+            return Ok(());
+        }
+    };
 
-        while current_row < row_count {
-            let tile = self
-                .get_tile(current_row, current_col)
-                .expect("Invalid board position");
+    let overlay = board.overlay(slope, start, args.flag("repeat"))?;
 
-            trees += match tile {
-                Tile::Empty => 0,
-                Tile::Tree => 1,
-            };
+    let output = match render {
+        "text" => format!("{}\n", e20d03::render_text(&overlay)).into_bytes(),
+        "ppm" => e20d03::render_ppm(&overlay, args.parse("scale")?.unwrap_or(4))?,
+        _ => return Err(Error::value("Unknown render format").with_text(render)),
+    };
 
-            current_row += row_speed;
-            current_col += col_speed;
-        }
+    let result = io::stdout().write_all(&output);
+    result.map_err(|error| Error::new(ErrorKind::Input, error.to_string()))?;
 
-        trees
-    }
+    Ok(())
 }
 
 fn main() {
-    let board = INPUT.parse::<Board>().expect("Could not parse board");
+    let args = Args::from_env();
 
-    let first: usize = board.count_trees(3, 1);
-    println!("Number of trees (first task): {}", first);
+    if let Err(error) = args.allow(OPTIONS) {
+        eprintln!("{error}");
+        process::exit(1);
+    }
 
-    let second = RUNS
+    let audit_only = OPTIONS
         .iter()
-        .map(|run| board.count_trees(run.0, run.1))
-        .product::<usize>();
+        .filter(|&&option| option != "format")
+        .any(|flag| args.flag(flag));
 
-    println!("Number of trees (second task): {}", second);
+    if !audit_only {
+        return advent_core::solver::run::<Puzzle>();
+    }
+
+    if let Err(error) = audit(&args) {
+        eprintln!("{error}");
+        process::exit(1);
+    }
 }
@@ -0,0 +1,144 @@
+use super::check_start;
+use super::Board;
+use super::Slope;
+use advent_core::bench::Xorshift;
+use advent_core::Result;
+
+const BITS: usize = u64::BITS as usize;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Packed {
+    words: Vec<u64>,
+    stride: usize,
+    width: usize,
+    height: usize,
+}
+
+struct Walk {
+    row: Option<isize>,
+    column: usize,
+    down: isize,
+    right: usize,
+    steps: usize,
+}
+
+impl Packed {
+    pub fn new(board: &Board) -> Result<Self> {
+        let width = board.tiles.width();
+        let height = board.tiles.height();
+        let stride = width.div_ceil(BITS);
+        let trees = board.legend.trees()?;
+
+        let mut words = vec![0; stride * height];
+
+        for ((row, column), tile) in board.tiles.cells() {
+            if trees[tile.0 as usize] {
+                words[row * stride + column / BITS] |= 1 << (column % BITS);
+            }
+        }
+
+        Ok(Packed {
+            words,
+            stride,
+            width,
+            height,
+        })
+    }
+
+    fn advance(&self, walk: &mut Walk, row: usize) -> bool {
+        if walk.steps == 0 {
+            walk.row = None;
+            return false;
+        }
+
+        walk.steps -= 1;
+        walk.column += walk.right;
+
+        if walk.column >= self.width {
+            walk.column -= self.width;
+        }
+
+        let tree = self.tree(row, walk.column);
+        walk.row = (row as isize).checked_add(walk.down);
+
+        tree
+    }
+
+    fn tree(&self, row: usize, column: usize) -> bool {
+        let word = self.words[row * self.stride + column / BITS];
+        word >> (column % BITS) & 1 == 1
+    }
+
+    pub fn count_trees(&self, slope: Slope, start: (usize, usize)) -> Result<usize> {
+        Ok(self.count_many(&[slope], start)?[0])
+    }
+
+    pub fn count_many(&self, slopes: &[Slope], start: (usize, usize)) -> Result<Vec<usize>> {
+        check_start(start, self.height, self.width)?;
+
+        let (first, origin) = start;
+        let width = self.width as isize;
+
+        let mut walks = slopes
+            .iter()
+            .map(|slope| Walk {
+                row: (first as isize).checked_add(slope.down),
+                column: origin,
+                down: slope.down,
+                right: slope.right.rem_euclid(width) as usize,
+                steps: steps(origin, slope.right),
+            })
+            .collect::<Vec<_>>();
+
+        let mut counts = vec![0; slopes.len()];
+
+        for row in first + 1..self.height {
+            for (walk, count) in walks.iter_mut().zip(&mut counts) {
+                if walk.row == Some(row as isize) {
+                    *count += self.advance(walk, row) as usize;
+                }
+            }
+        }
+
+        for (walk, count) in walks.iter_mut().zip(&mut counts) {
+            while walk.down < 0 {
+                match walk.row {
+                    Some(row) if row >= 0 => *count += self.advance(walk, row as usize) as usize,
+                    _ => break,
+                }
+            }
+        }
+
+        Ok(counts)
+    }
+}
+
+#[doc(hidden)]
+pub fn synthetic(width: usize, height: usize, seed: u64) -> String {
+    let mut random = Xorshift::new(seed);
+    let mut text = String::with_capacity((width + 1) * height);
+
+    for _ in 0..height {
+        for _ in 0..width {
+            text.push(match random.below(4) {
+                0 => '#',
+                _ => '.',
+            });
+        }
+
+        text.push('\n');
+    }
+
+    text
+}
+
+// Board::trail stops once the unwrapped column leaves isize, so walks do too.
+fn steps(origin: usize, right: isize) -> usize {
+    let room = match right {
+        0.. => isize::MAX as i128 - origin as i128,
+        _ => origin as i128 - isize::MIN as i128,
+    };
+
+    room.checked_div(right.unsigned_abs() as i128)
+        .map_or(usize::MAX, |steps| steps as usize)
+}
@@ -0,0 +1,138 @@
+use super::Board;
+use super::Slope;
+use advent_core::grid::Grid;
+use advent_core::Error;
+use advent_core::Result;
+use std::iter;
+
+const MAX_CELLS: usize = 1 << 24;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mark {
+    Open,
+    Tree,
+    Passed,
+    Hit,
+}
+
+impl Mark {
+    pub fn symbol(self) -> char {
+        match self {
+            Mark::Open => '.',
+            Mark::Tree => '#',
+            Mark::Passed => 'X',
+            Mark::Hit => 'O',
+        }
+    }
+
+    pub fn color(self) -> [u8; 3] {
+        match self {
+            Mark::Open => [240, 240, 240],
+            Mark::Tree => [34, 110, 50],
+            Mark::Passed => [60, 120, 220],
+            Mark::Hit => [220, 40, 40],
+        }
+    }
+}
+
+impl Board {
+    pub fn overlay(&self, slope: Slope, start: (usize, usize), repeat: bool) -> Result<Grid<Mark>> {
+        self.check_start(start)?;
+
+        let width = self.tiles.width();
+        let trail = self.trail(slope, start).collect::<Vec<_>>();
+
+        let (origin, columns) = match (repeat, width) {
+            (false, _) | (true, 0) => (0, width),
+            (true, _) => {
+                let columns = trail.iter().map(|(_, column)| *column);
+                let left = columns.clone().min().unwrap_or(0).min(0).unsigned_abs();
+                let right = columns.max().unwrap_or(0).max(0) as usize + 1;
+
+                let origin = left.div_ceil(width) * width;
+                let end = origin.checked_add(right);
+                let columns = end.and_then(|end| end.div_ceil(width).checked_mul(width));
+
+                (origin, columns.ok_or_else(too_wide)?)
+            }
+        };
+
+        let cells = columns
+            .checked_mul(self.tiles.height())
+            .filter(|&cells| cells <= MAX_CELLS)
+            .ok_or_else(too_wide)?;
+        let mut cells = Vec::with_capacity(cells);
+
+        for row in 0..self.tiles.height() as isize {
+            for column in 0..columns as isize {
+                let tile = self.tiles.locate(row, column - origin as isize);
+                let tile = tile.and_then(|(row, column)| self.tiles.get(row, column));
+
+                cells.push(match tile.map(|tile| self.legend.kind(*tile).is_tree()) {
+                    Some(true) => Mark::Tree,
+                    _ => Mark::Open,
+                });
+            }
+        }
+
+        let mut grid = Grid::new(columns, cells)?;
+
+        for (row, column) in trail {
+            let column = match repeat {
+                true => column + origin as isize,
+                false => column.rem_euclid(width.max(1) as isize),
+            };
+
+            if let Some(mark) = grid.get_mut(row as usize, column as usize) {
+                *mark = match mark {
+                    Mark::Tree | Mark::Hit => Mark::Hit,
+                    Mark::Open | Mark::Passed => Mark::Passed,
+                };
+            }
+        }
+
+        Ok(grid)
+    }
+}
+
+pub fn render_text(grid: &Grid<Mark>) -> String {
+    let rows = grid
+        .rows()
+        .map(|row| row.iter().map(|mark| mark.symbol()).collect());
+    rows.collect::<Vec<String>>().join("\n")
+}
+
+fn too_wide() -> Error {
+    Error::value("Overlay too wide")
+}
+
+pub fn render_ppm(grid: &Grid<Mark>, scale: usize) -> Result<Vec<u8>> {
+    let scale = scale.max(1);
+    let width = grid.width().checked_mul(scale);
+    let height = grid.height().checked_mul(scale);
+    let pixels = width
+        .zip(height)
+        .and_then(|(width, height)| width.checked_mul(height));
+
+    if pixels.is_none_or(|pixels| pixels > MAX_CELLS) {
+        return Err(Error::value("Image too large").with_text(scale.to_string()));
+    }
+
+    let (width, height) = (grid.width() * scale, grid.height() * scale);
+    let header = format!("P6\n{width} {height}\n255\n");
+    let mut image = header.into_bytes();
+
+    for row in grid.rows() {
+        let line = row
+            .iter()
+            .flat_map(|mark| iter::repeat_n(mark.color(), scale))
+            .flatten()
+            .collect::<Vec<_>>();
+
+        for _ in 0..scale {
+            image.extend_from_slice(&line);
+        }
+    }
+
+    Ok(image)
+}
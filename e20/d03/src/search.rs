@@ -0,0 +1,59 @@
+use super::Board;
+use super::Slope;
+use advent_core::Error;
+use advent_core::Result;
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ranking {
+    pub slopes: Vec<(Slope, usize)>,
+}
+
+impl Ranking {
+    pub fn min(&self) -> Option<(Slope, usize)> {
+        self.slopes.first().copied()
+    }
+
+    pub fn max(&self) -> Option<(Slope, usize)> {
+        let most = self.slopes.last()?.1;
+        self.slopes
+            .iter()
+            .find(|(_, trees)| *trees == most)
+            .copied()
+    }
+
+    pub fn distribution(&self) -> BTreeMap<usize, usize> {
+        let mut distribution = BTreeMap::new();
+
+        for (_, trees) in &self.slopes {
+            *distribution.entry(*trees).or_insert(0) += 1;
+        }
+
+        distribution
+    }
+}
+
+impl Board {
+    pub fn search(&self, max_right: usize, max_down: usize) -> Result<Ranking> {
+        let width = self.tiles.width().max(1);
+
+        if max_down > self.tiles.height() {
+            let text = max_down.to_string();
+            return Err(Error::value("Search deeper than the board").with_text(text));
+        }
+
+        let max_right = max_right.min(width - 1) as isize;
+        let mut slopes = vec![];
+
+        for down in 1..=max_down as isize {
+            for right in 0..=max_right {
+                let slope = Slope { right, down };
+                slopes.push((slope, self.count_trees(slope, (0, 0))?));
+            }
+        }
+
+        slopes.sort_by_key(|(slope, trees)| (*trees, slope.down, slope.right));
+
+        Ok(Ranking { slopes })
+    }
+}
@@ -0,0 +1,255 @@
+use crate::render_ppm;
+use crate::render_text;
+use crate::synthetic;
+use crate::Board;
+use crate::Legend;
+use crate::Mark;
+use crate::Packed;
+use crate::Slope;
+
+const SAMPLE: &str = "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+fn trees(slope: &str, start: (usize, usize)) -> usize {
+    let board = SAMPLE.parse::<Board>().unwrap();
+    board.count_trees(slope.parse().unwrap(), start).unwrap()
+}
+
+#[test]
+fn test_sample() {
+    let counts = ["1,1", "3,1", "5,1", "7,1", "1,2"].map(|slope| trees(slope, (0, 0)));
+    assert_eq!(counts, [2, 7, 3, 4, 2]);
+}
+
+#[test]
+fn test_slopes() {
+    assert_eq!(trees("-3,1", (0, 0)), trees("8,1", (0, 0)));
+    assert_eq!(trees("-3,-1", (10, 0)), 3);
+    assert_eq!(trees("1/2", (0, 0)), trees("1,2", (0, 0)));
+    assert_eq!(trees("2/4", (0, 0)), trees("1,2", (0, 0)));
+    assert_eq!(trees("3,1", (10, 10)), 0);
+
+    assert_eq!("-4/6".parse(), Ok(Slope { right: -2, down: 3 }));
+    assert!("3,0".parse::<Slope>().is_err());
+    assert!("0/0".parse::<Slope>().is_err());
+    assert!("3".parse::<Slope>().is_err());
+
+    let min = isize::MIN;
+    assert_eq!(
+        Slope::ratio(min, min),
+        Ok(Slope {
+            right: -1,
+            down: -1
+        })
+    );
+    assert_eq!(
+        Slope::ratio(min, -1),
+        Ok(Slope {
+            right: min,
+            down: -1
+        })
+    );
+    assert_eq!(trees(&format!("{},1", isize::MAX), (0, 0)), 0);
+    assert_eq!(trees(&format!("{min},{min}"), (10, 10)), 0);
+
+    let board = SAMPLE.parse::<Board>().unwrap();
+    let steep = Slope {
+        right: 1,
+        down: isize::MAX,
+    };
+    assert_eq!(board.path(steep, (0, 0)).count(), 0);
+    assert_eq!(
+        board
+            .path(
+                Slope {
+                    right: min,
+                    down: 1
+                },
+                (0, 0)
+            )
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_search() {
+    let board = SAMPLE.parse::<Board>().unwrap();
+    let ranking = board.search(14, 2).unwrap();
+
+    assert_eq!(ranking.slopes.len(), 22);
+    assert_eq!(board.search(10, 2), Ok(ranking.clone()));
+    assert_eq!(board.search(usize::MAX, 11).unwrap().slopes.len(), 121);
+    assert!(board.search(1, 12).is_err());
+    assert!(board.search(1, usize::MAX).is_err());
+    assert_eq!(ranking.min(), Some((Slope { right: 5, down: 2 }, 0)));
+    assert_eq!(ranking.max(), Some((Slope { right: 3, down: 1 }, 7)));
+
+    for (slope, trees) in &ranking.slopes {
+        assert_eq!(board.count_trees(*slope, (0, 0)), Ok(*trees));
+    }
+
+    let distribution = ranking.distribution();
+    assert_eq!(distribution.values().sum::<usize>(), 22);
+    assert_eq!(distribution.get(&7), Some(&1));
+}
+
+#[test]
+fn test_render() {
+    let board = SAMPLE.parse::<Board>().unwrap();
+    let slope = Slope::new(3, 1).unwrap();
+
+    let repeated = render_text(&board.overlay(slope, (0, 0), true).unwrap());
+    let lines = repeated.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 11);
+    assert_eq!(lines[1], "#..X#...#..#...#...#..#...#...#..");
+    assert_eq!(lines[2], ".#....O..#..#....#..#..#....#..#.");
+    assert_eq!(lines[10], ".#..#...#.#.#..#...#.#.#..#...O.#");
+    assert_eq!(repeated.matches('O').count(), 7);
+
+    let wrapped = render_text(&board.overlay(slope, (0, 0), false).unwrap());
+    assert_eq!(wrapped.lines().nth(4), Some(".O...##..#."));
+    assert_eq!(wrapped.matches(['O', 'X']).count(), 10);
+
+    let left = board
+        .overlay(Slope::new(-1, 1).unwrap(), (0, 0), true)
+        .unwrap();
+    assert_eq!(left.width(), 22);
+    let left = render_text(&left);
+    let lines = left.lines().collect::<Vec<_>>();
+    assert_eq!(&lines[1][9..12], ".X#");
+    assert_eq!(&lines[2][8..11], ".O.");
+
+    let wrapped = board.overlay(slope, (0, 0), false).unwrap();
+    let image = render_ppm(&wrapped, 2).unwrap();
+    let header = b"P6\n22 22\n255\n";
+    assert_eq!(&image[..header.len()], header);
+    assert_eq!(image.len(), header.len() + 22 * 22 * 3);
+    assert!(render_ppm(&wrapped, usize::MAX).is_err());
+    assert!(render_ppm(&wrapped, 1 << 12).is_err());
+
+    let wide = board.overlay(Slope::new(isize::MAX, 1).unwrap(), (0, 0), true);
+    assert_eq!(wide.unwrap_err().message(), "Overlay too wide");
+    let steep = Slope::new(1_000_000, 1).unwrap();
+    assert!(board.overlay(steep, (0, 0), true).is_err());
+}
+
+#[test]
+fn test_invalid() {
+    let error = |input: &str| input.parse::<Board>().unwrap_err();
+
+    let invalid = error("..#\n.x.\n#..");
+    assert_eq!(invalid.message(), "Invalid character");
+    assert_eq!((invalid.line(), invalid.column()), (Some(2), Some(2)));
+    assert_eq!(invalid.text(), Some("x"));
+
+    let ragged = error("..#\n..\n#..");
+    assert_eq!(ragged.message(), "Ragged grid row");
+    assert_eq!(ragged.line(), Some(2));
+
+    assert_eq!(error("..#\n\n#..").line(), Some(2));
+    assert_eq!(error("").message(), "Empty map");
+    assert_eq!(error("\n\n").message(), "Empty map");
+
+    let board = SAMPLE.parse::<Board>().unwrap();
+    let slope = Slope::new(3, 1).unwrap();
+
+    assert!(board.count_trees(slope, (11, 0)).is_err());
+    assert!(board.count_trees(slope, (0, 11)).is_err());
+    assert!(board.overlay(slope, (0, 11), false).is_err());
+}
+
+#[test]
+fn test_legend() {
+    let legend = "^=rock:5 #=tree:1 *=snow .=open".parse::<Legend>().unwrap();
+    let terrain = "..^*\n#.*^\n*^#.\n.#*^";
+    let board = Board::parse_with(terrain, legend.clone()).unwrap();
+    let slope = Slope::new(1, 1).unwrap();
+
+    assert_eq!(board.cost(slope, (0, 0)), Ok(6));
+    assert_eq!(board.count_trees(slope, (0, 0)), Ok(1));
+    assert_eq!(board.cost(Slope::new(3, 1).unwrap(), (0, 0)), Ok(7));
+
+    let sample = SAMPLE.parse::<Board>().unwrap();
+    let slope = Slope::new(3, 1).unwrap();
+    assert_eq!(sample.cost(slope, (0, 0)), Ok(7));
+
+    let heavy = Legend::default().with('#', "tree", 3).unwrap();
+    let heavy = Board::parse_with(SAMPLE, heavy).unwrap();
+    assert_eq!(heavy.cost(slope, (0, 0)), Ok(21));
+
+    let error = Board::parse_with("..~", legend).unwrap_err();
+    assert_eq!((error.column(), error.text()), (Some(3), Some("~")));
+
+    assert!("#tree:1".parse::<Legend>().is_err());
+    assert!("#=tree:x".parse::<Legend>().is_err());
+    assert!("#=:1".parse::<Legend>().is_err());
+    assert!("".parse::<Legend>().is_err());
+
+    let forest = "#=tree:1 T=tree:1 .=open".parse::<Legend>().unwrap();
+    let board = Board::parse_with("T.\n.#\nT.", forest).unwrap();
+    let diagonal = Slope::new(1, 1).unwrap();
+    let overlay = board.overlay(diagonal, (0, 0), false).unwrap();
+    let hits = overlay.cells().filter(|(_, mark)| **mark == Mark::Hit);
+    assert_eq!(board.count_trees(diagonal, (0, 0)), Ok(2));
+    assert_eq!(hits.count(), 2);
+    assert_eq!(overlay.get(0, 0), Some(&Mark::Tree));
+
+    let barren = "^=rock:5 .=open".parse::<Legend>().unwrap();
+    let board = Board::parse_with("^.\n.^", barren).unwrap();
+    assert!(board.count_trees(slope, (0, 0)).is_err());
+    assert!(Packed::new(&board).is_err());
+
+    let symbols = (0..256).map(|index| char::from_u32(0x100 + index).unwrap());
+    let full = symbols.fold(Legend::empty(), |legend, chr| {
+        legend.with(chr, "tree", 1).unwrap()
+    });
+    assert!(full.clone().with('\u{100}', "open", 0).is_ok());
+    assert!(full.with('#', "tree", 1).is_err());
+}
+
+#[test]
+fn test_packed() {
+    let (min, max) = (isize::MIN, isize::MAX);
+    let slopes = [
+        "1,1", "3,1", "5,1", "7,1", "1,2", "-3,1", "2,-1", "70,3", "0,5", "1,-9",
+    ]
+    .iter()
+    .map(|slope| slope.parse::<Slope>().unwrap())
+    .chain(
+        [(1, max), (max, 1), (min, 1), (min, min), (1, min)]
+            .iter()
+            .map(|&(right, down)| Slope::new(right, down).unwrap()),
+    )
+    .collect::<Vec<_>>();
+
+    for (width, height, seed) in [(11, 11, 1), (64, 40, 2), (65, 30, 3), (200, 17, 4)] {
+        let board = synthetic(width, height, seed).parse::<Board>().unwrap();
+        let packed = Packed::new(&board).unwrap();
+
+        for start in [(0, 0), (height - 1, width - 1), (height / 2, 3)] {
+            let expected = slopes
+                .iter()
+                .map(|&slope| board.count_trees(slope, start).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(packed.count_many(&slopes, start).unwrap(), expected);
+            assert_eq!(packed.count_trees(slopes[1], start), Ok(expected[1]));
+        }
+
+        assert!(packed.count_many(&slopes, (height, 0)).is_err());
+    }
+
+    let sample = Packed::new(&SAMPLE.parse::<Board>().unwrap()).unwrap();
+    let counts = sample.count_many(&slopes[..5], (0, 0)).unwrap();
+    assert_eq!(counts, [2, 7, 3, 4, 2]);
+}
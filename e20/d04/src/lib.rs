@@ -0,0 +1,151 @@
+use advent_core::Error;
+use advent_core::Result;
+use advent_core::Solver;
+use std::str::FromStr;
+
+pub use self::report::*;
+pub use self::schema::*;
+
+mod report;
+mod schema;
+
+#[cfg(test)]
+mod tests;
+
+const INPUT: &str = "input/2020/04.txt";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Passport {
+    pub lines: (usize, usize),
+    entries: Vec<(String, String, usize)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Database {
+    pub schema: Schema,
+    pub passports: Vec<Passport>,
+}
+
+fn split_entry(entry: &str) -> Result<(&str, &str)> {
+    let split = entry.splitn(2, ':').collect::<Vec<_>>();
+
+    match split[..] {
+        [key, value] => Ok((key, value)),
+        _ => Err(Error::syntax("Invalid entry").with_text(entry)),
+    }
+}
+
+impl FromStr for Passport {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Passport::parse_at(s, 1)
+    }
+}
+
+impl Passport {
+    pub fn parse_at(text: &str, first: usize) -> Result<Self> {
+        let mut entries = vec![];
+        let mut last = first;
+
+        for (line, row) in (first..).zip(text.lines()) {
+            for word in row.split_whitespace() {
+                let (key, value) = split_entry(word).map_err(|error| error.with_line(line))?;
+                entries.push((key.into(), value.into(), line));
+            }
+
+            last = line;
+        }
+
+        let lines = (first, last);
+
+        Ok(Passport { lines, entries })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        let entry = self.entries.iter().rev().find(|(known, ..)| known == key);
+        entry.map(|(_, value, _)| value.as_str())
+    }
+
+    pub fn line(&self, key: &str) -> Option<usize> {
+        let entry = self.entries.iter().rev().find(|(known, ..)| known == key);
+        entry.map(|(.., line)| *line)
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &str)> + '_ {
+        self.entries
+            .iter()
+            .map(|(key, value, _)| (key.as_str(), value.as_str()))
+    }
+}
+
+fn split(input: &str) -> Vec<(usize, String)> {
+    let mut blocks: Vec<(usize, String)> = vec![];
+    let mut open = false;
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+
+        match (line.is_empty(), open, blocks.last_mut()) {
+            (true, _, _) => open = false,
+            (false, true, Some((_, text))) => {
+                text.push('\n');
+                text.push_str(line);
+            }
+            (false, _, _) => {
+                blocks.push((index + 1, line.to_string()));
+                open = true;
+            }
+        }
+    }
+
+    blocks
+}
+
+impl Database {
+    pub fn parse(input: &str, schema: Schema) -> Result<Self> {
+        let passports = split(input)
+            .into_iter()
+            .map(|(first, text)| schema.passport(&text, first))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Database { schema, passports })
+    }
+
+    pub fn complete(&self) -> usize {
+        let complete = self.passports.iter();
+        complete
+            .filter(|passport| self.schema.complete(passport).is_ok())
+            .count()
+    }
+
+    pub fn valid(&self) -> usize {
+        let valid = self.passports.iter();
+        valid
+            .filter(|passport| self.schema.validate(passport).is_ok())
+            .count()
+    }
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const EVENT: u16 = 2020;
+    const DAY: u8 = 4;
+    const INPUT: &'static str = INPUT;
+
+    type Input = Database;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Database::parse(input, Schema::default())
+    }
+
+    fn part_one(database: &Self::Input) -> Result<Self::Answer> {
+        Ok(database.complete())
+    }
+
+    fn part_two(database: &Self::Input) -> Result<Self::Answer> {
+        Ok(database.valid())
+    }
+}
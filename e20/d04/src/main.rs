@@ -1,163 +1,73 @@
-use self::types::*;
-use std::convert::TryFrom;
-use std::str::FromStr;
-
-mod types;
-
-const INPUT: &str = include_str!("input.txt");
-
-#[allow(dead_code)]
-#[derive(Clone, Debug)]
-pub struct ParserError(&'static str);
-
-pub type ParserResult<T> = Result<T, ParserError>;
-
-#[derive(Clone, Debug, Default)]
-struct OptPassport {
-    byr: Option<String>,
-    iyr: Option<String>,
-    eyr: Option<String>,
-    hgt: Option<String>,
-    hcl: Option<String>,
-    ecl: Option<String>,
-    pid: Option<String>,
-    cid: Option<String>,
-}
+use advent_core::args::Args;
+use advent_core::input::Source;
+use advent_core::Error;
+use advent_core::Result;
+use advent_core::Solver;
+use e20d04::Database;
+use e20d04::Puzzle;
+use e20d04::Schema;
+use std::process;
+
+const OPTIONS: &[&str] = &["format", "schema", "report"];
+
+fn report(database: &Database) {
+    let failures = database.failures();
 
-impl OptPassport {
-    fn split_entry(entry: &str) -> ParserResult<(&str, &str)> {
-        let split = entry.splitn(2, ':').collect::<Vec<_>>();
+    for failure in &failures {
+        let (first, last) = failure.lines;
+        let errors = failure.errors.iter().map(|error| error.to_string());
 
-        match split[..] {
-            [key, value] => Ok((key, value)),
-            _ => Err(ParserError("Invalid entry")),
+        println!("Passport {} (lines {first}-{last}):", failure.index);
+
+        for error in errors {
+            println!("  {error}");
         }
     }
-}
 
-impl FromStr for OptPassport {
-    type Err = ParserError;
-
-    fn from_str(s: &str) -> ParserResult<Self> {
-        let mut builder = OptPassport::default();
-
-        let pairs = s
-            .split_whitespace()
-            .map(|text| text.trim())
-            .filter(|text| !text.is_empty())
-            .map(OptPassport::split_entry)
-            .collect::<ParserResult<Vec<_>>>()?;
-
-        for (key, value) in pairs {
-            match key {
-                "byr" => builder.byr = Some(value.into()),
-                "iyr" => builder.iyr = Some(value.into()),
-                "eyr" => builder.eyr = Some(value.into()),
-                "hgt" => builder.hgt = Some(value.into()),
-                "hcl" => builder.hcl = Some(value.into()),
-                "ecl" => builder.ecl = Some(value.into()),
-                "pid" => builder.pid = Some(value.into()),
-                "cid" => builder.cid = Some(value.into()),
-                _ => return Err(ParserError("Invalid key")),
-            }
-        }
+    println!("Most common failures:");
 
-        Ok(builder)
+    for (message, count) in e20d04::summarize(&failures) {
+        println!("{count:>6}  {message}");
     }
 }
 
-#[derive(Clone, Debug)]
-struct RawPassport {
-    byr: String,
-    iyr: String,
-    eyr: String,
-    hgt: String,
-    hcl: String,
-    ecl: String,
-    pid: String,
-    cid: Option<String>,
-}
+fn audit(args: &Args) -> Result<()> {
+    let schema = match (args.flag("schema"), args.value("schema")) {
+        (_, Some(path)) => Source::new(Some(path), "").read()?.parse::<Schema>()?,
+        (true, None) => return Err(Error::syntax("Expected --schema=<path>")),
+        (false, None) => Schema::default(),
+    };
 
-impl TryFrom<OptPassport> for RawPassport {
-    type Error = ParserError;
-
-    fn try_from(value: OptPassport) -> ParserResult<Self> {
-        Ok(RawPassport {
-            byr: value.byr.ok_or(ParserError("Missing byr value"))?,
-            iyr: value.iyr.ok_or(ParserError("Missing iyr value"))?,
-            eyr: value.eyr.ok_or(ParserError("Missing eyr value"))?,
-            hgt: value.hgt.ok_or(ParserError("Missing hgt value"))?,
-            hcl: value.hcl.ok_or(ParserError("Missing hcl value"))?,
-            ecl: value.ecl.ok_or(ParserError("Missing ecl value"))?,
-            pid: value.pid.ok_or(ParserError("Missing pid value"))?,
-            cid: value.cid,
-        })
+    let input = args.positional().first().map(String::as_str);
+    let text = Source::new(input, Puzzle::INPUT).read()?;
+    let database = Database::parse(&text, schema)?;
+
+    if args.flag("report") {
+        report(&database);
+        return Ok(());
     }
-}
 
-#[allow(dead_code)]
-#[derive(Clone, Debug)]
-struct Passport {
-    byr: BirthYear,
-    iyr: IssueYear,
-    eyr: ExpirationYear,
-    hgt: Height,
-    hcl: HairColor,
-    ecl: EyeColor,
-    pid: PassportId,
-    cid: Option<CountryId>,
-}
+    let total = database.passports.len();
+    println!("Complete: {} of {total}", database.complete());
+    println!("Valid: {} of {total}", database.valid());
 
-impl TryFrom<RawPassport> for Passport {
-    type Error = ParserError;
-
-    fn try_from(value: RawPassport) -> ParserResult<Self> {
-        let country = match value.cid {
-            Some(text) => Some(text.parse()?),
-            None => None,
-        };
-
-        Ok(Passport {
-            byr: value.byr.parse()?,
-            iyr: value.iyr.parse()?,
-            eyr: value.eyr.parse()?,
-            hgt: value.hgt.parse()?,
-            hcl: value.hcl.parse()?,
-            ecl: value.ecl.parse()?,
-            pid: value.pid.parse()?,
-            cid: country,
-        })
-    }
+    Ok(())
 }
 
 fn main() {
-    let items = INPUT
-        .split("\n\n")
-        .map(|text| text.trim())
-        .filter(|text| !text.is_empty())
-        .collect::<Vec<&str>>();
-
-    let opts = items
-        .into_iter()
-        .map(OptPassport::from_str)
-        .collect::<ParserResult<Vec<_>>>()
-        .expect("Invalid passport item");
-
-    println!("Opt passports: {}", opts.len());
-
-    let raws = opts
-        .into_iter()
-        .map(RawPassport::try_from)
-        .filter_map(Result::ok)
-        .collect::<Vec<_>>();
-
-    println!("Raw passports: {}", raws.len());
-
-    let valid = raws
-        .into_iter()
-        .map(Passport::try_from)
-        .filter_map(Result::ok)
-        .collect::<Vec<_>>();
-
-    println!("Valid Passports: {}", valid.len());
+    let args = Args::from_env();
+
+    if let Err(error) = args.allow(OPTIONS) {
+        eprintln!("{error}");
+        process::exit(1);
+    }
+
+    if !["schema", "report"].iter().any(|flag| args.flag(flag)) {
+        return advent_core::solver::run::<Puzzle>();
+    }
+
+    if let Err(error) = audit(&args) {
+        eprintln!("{error}");
+        process::exit(1);
+    }
 }
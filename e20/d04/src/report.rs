@@ -0,0 +1,44 @@
+use super::Database;
+use advent_core::Error;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    pub index: usize,
+    pub lines: (usize, usize),
+    pub errors: Vec<Error>,
+}
+
+impl Database {
+    pub fn failures(&self) -> Vec<Failure> {
+        self.passports
+            .iter()
+            .enumerate()
+            .filter_map(|(index, passport)| {
+                let errors = self.schema.problems(passport);
+
+                (!errors.is_empty()).then(|| Failure {
+                    index: index + 1,
+                    lines: passport.lines,
+                    errors,
+                })
+            })
+            .collect()
+    }
+}
+
+pub fn summarize(failures: &[Failure]) -> Vec<(String, usize)> {
+    let mut counts = HashMap::new();
+
+    for error in failures.iter().flat_map(|failure| &failure.errors) {
+        *counts.entry(error.message()).or_insert(0) += 1;
+    }
+
+    let mut summary = counts
+        .into_iter()
+        .map(|(message, count)| (message.to_string(), count))
+        .collect::<Vec<_>>();
+
+    summary.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    summary
+}
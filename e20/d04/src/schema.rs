@@ -0,0 +1,272 @@
+use super::Passport;
+use advent_core::Error;
+use advent_core::Result;
+use std::str::FromStr;
+
+const SCHEMA: &str = include_str!("schema.txt");
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rule {
+    Any,
+    Range(u64, u64),
+    Units(Vec<(String, u64, u64)>),
+    Hex(usize),
+    Digits(usize),
+    OneOf(Vec<String>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Field {
+    pub name: String,
+    pub required: bool,
+    pub rule: Rule,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schema {
+    fields: Vec<Field>,
+}
+
+fn range(text: &str) -> Result<(u64, u64)> {
+    let invalid = || Error::syntax("Expected range like 1-9").with_text(text);
+    let (min, max) = text.split_once('-').ok_or_else(invalid)?;
+    let (min, max) = (min.parse()?, max.parse()?);
+
+    match min <= max {
+        true => Ok((min, max)),
+        false => Err(Error::syntax("Inverted range").with_text(text)),
+    }
+}
+
+fn unit(text: &str) -> Result<(String, u64, u64)> {
+    let split = text.trim_end_matches(|chr: char| chr.is_alphabetic());
+    let (min, max) = range(split)?;
+
+    match &text[split.len()..] {
+        "" => Err(Error::syntax("Missing unit").with_text(text)),
+        unit => Ok((unit.to_string(), min, max)),
+    }
+}
+
+impl Rule {
+    fn parse(name: &str, args: &[&str]) -> Result<Self> {
+        let count = |text: &str| text.parse::<usize>().map_err(Error::from);
+
+        match (name, args) {
+            ("any", []) => Ok(Rule::Any),
+            ("range", [text]) => range(text).map(|(min, max)| Rule::Range(min, max)),
+            ("units", [_, ..]) => args
+                .iter()
+                .map(|text| unit(text))
+                .collect::<Result<_>>()
+                .map(Rule::Units),
+            ("hex", [text]) => count(text).map(Rule::Hex),
+            ("digits", [text]) => count(text).map(Rule::Digits),
+            ("one-of", [_, ..]) => Ok(Rule::OneOf(
+                args.iter().map(|arg| arg.to_string()).collect(),
+            )),
+            _ => Err(Error::syntax("Invalid rule").with_text(name)),
+        }
+    }
+
+    pub fn check(&self, field: &str, value: &str) -> Result<()> {
+        let number = |text: &str| {
+            let invalid = |_| Error::value(format!("Invalid {field} number"));
+            text.parse::<u64>().map_err(invalid)
+        };
+        let digits = |text: &str| text.chars().all(|chr| chr.is_ascii_digit());
+
+        match self {
+            Rule::Any => Ok(()),
+            Rule::Range(min, max) => match (*min..=*max).contains(&number(value)?) {
+                true => Ok(()),
+                false => Err(Error::value(format!("Invalid {field} range"))),
+            },
+            Rule::Units(units) => {
+                let found = units.iter().find_map(|(unit, min, max)| {
+                    let amount = value.strip_suffix(unit.as_str())?;
+                    Some((amount, min, max))
+                });
+
+                let Some((amount, min, max)) = found else {
+                    return Err(Error::value(format!("Invalid {field} format")));
+                };
+
+                match (*min..=*max).contains(&number(amount)?) {
+                    true => Ok(()),
+                    false => Err(Error::value(format!("Invalid {field} range"))),
+                }
+            }
+            Rule::Hex(length) => {
+                let Some(hex) = value.strip_prefix('#') else {
+                    return Err(Error::value(format!("Missing {field} prefix")));
+                };
+
+                if !hex.chars().all(|chr| matches!(chr, '0'..='9' | 'a'..='f')) {
+                    return Err(Error::value(format!("Invalid {field} character")));
+                }
+
+                match hex.len() == *length {
+                    true => Ok(()),
+                    false => Err(Error::value(format!("Invalid {field} length"))),
+                }
+            }
+            Rule::Digits(length) => {
+                if !digits(value) {
+                    return Err(Error::value(format!("Invalid {field} character")));
+                }
+
+                match value.len() == *length {
+                    true => Ok(()),
+                    false => Err(Error::value(format!("Invalid {field} length"))),
+                }
+            }
+            Rule::OneOf(options) => match options.iter().any(|option| option == value) {
+                true => Ok(()),
+                false => Err(Error::value(format!("Invalid {field} value"))),
+            },
+        }
+    }
+}
+
+impl FromStr for Field {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let words = s.split_whitespace().collect::<Vec<_>>();
+
+        let [name, presence, rule, args @ ..] = &words[..] else {
+            return Err(Error::syntax("Expected name, presence and rule").with_text(s));
+        };
+
+        let required = match *presence {
+            "required" => true,
+            "optional" => false,
+            _ => return Err(Error::syntax("Invalid presence").with_text(*presence)),
+        };
+
+        Ok(Field {
+            name: name.to_string(),
+            required,
+            rule: Rule::parse(rule, args)?,
+        })
+    }
+}
+
+impl Default for Schema {
+    fn default() -> Self {
+        SCHEMA.parse().expect("Invalid built-in schema")
+    }
+}
+
+impl FromStr for Schema {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut schema = Schema::empty();
+
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+
+            let located = |error: Error| error.with_line(index + 1).with_text(line);
+            let field = line.parse::<Field>().map_err(located)?;
+
+            if schema.field(&field.name).is_some() {
+                return Err(located(Error::syntax("Duplicate field")));
+            }
+
+            schema = schema.with(field);
+        }
+
+        Ok(schema)
+    }
+}
+
+impl Schema {
+    pub fn empty() -> Self {
+        Schema { fields: vec![] }
+    }
+
+    pub fn with(mut self, field: Field) -> Self {
+        self.fields.retain(|known| known.name != field.name);
+        self.fields.push(field);
+        self
+    }
+
+    pub fn required(self, name: &str, rule: Rule) -> Self {
+        let name = name.to_string();
+        self.with(Field {
+            name,
+            required: true,
+            rule,
+        })
+    }
+
+    pub fn optional(self, name: &str, rule: Rule) -> Self {
+        let name = name.to_string();
+        self.with(Field {
+            name,
+            required: false,
+            rule,
+        })
+    }
+
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    pub fn passport(&self, text: &str, first: usize) -> Result<Passport> {
+        let passport = Passport::parse_at(text, first)?;
+
+        for (key, _) in passport.entries() {
+            if self.field(key).is_none() {
+                let error = Error::syntax("Invalid key").with_text(key);
+                return Err(error.with_line(passport.line(key).unwrap_or(first)));
+            }
+        }
+
+        Ok(passport)
+    }
+
+    fn missing<'a>(&'a self, passport: &'a Passport) -> impl Iterator<Item = Error> + 'a {
+        self.fields
+            .iter()
+            .filter(move |field| field.required && passport.get(&field.name).is_none())
+            .map(|field| Error::missing(format!("Missing {} value", field.name)))
+    }
+
+    fn invalid<'a>(&'a self, passport: &'a Passport) -> impl Iterator<Item = Error> + 'a {
+        self.fields.iter().filter_map(move |field| {
+            let value = passport.get(&field.name)?;
+            let error = field.rule.check(&field.name, value).err()?;
+            let line = passport.line(&field.name).unwrap_or(passport.lines.0);
+
+            Some(error.with_line(line).with_text(value))
+        })
+    }
+
+    pub fn complete(&self, passport: &Passport) -> Result<()> {
+        self.missing(passport).next().map_or(Ok(()), Err)
+    }
+
+    pub fn validate(&self, passport: &Passport) -> Result<()> {
+        self.missing(passport)
+            .chain(self.invalid(passport))
+            .next()
+            .map_or(Ok(()), Err)
+    }
+
+    pub fn problems(&self, passport: &Passport) -> Vec<Error> {
+        self.missing(passport)
+            .chain(self.invalid(passport))
+            .collect()
+    }
+}
@@ -0,0 +1,219 @@
+use crate::summarize;
+use crate::Database;
+use crate::Passport;
+use crate::Rule;
+use crate::Schema;
+
+const SAMPLE: &str = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in";
+
+const INVALID: &str = "eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007
+
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f";
+
+fn check(schema: &Schema, line: &str) -> Result<(), String> {
+    let passport = line.parse::<Passport>().unwrap();
+    let result = schema.validate(&passport);
+
+    result.map_err(|error| error.message().to_string())
+}
+
+#[test]
+fn test_sample() {
+    let database = Database::parse(SAMPLE, Schema::default()).unwrap();
+    assert_eq!((database.complete(), database.valid()), (2, 2));
+
+    let database = Database::parse(INVALID, Schema::default()).unwrap();
+    assert_eq!((database.complete(), database.valid()), (5, 1));
+
+    assert!(Database::parse("byr:2000 xyz:1", Schema::default()).is_err());
+}
+
+#[test]
+fn test_schema() {
+    let schema = Schema::default();
+
+    assert_eq!(schema.fields().len(), 8);
+    assert_eq!(schema.field("byr").unwrap().rule, Rule::Range(1920, 2002));
+    assert!(!schema.field("cid").unwrap().required);
+
+    let built = Schema::empty()
+        .required("byr", Rule::Range(1900, 2010))
+        .optional("hgt", Rule::Units(vec![("cm".into(), 100, 200)]));
+
+    assert_eq!(check(&built, "byr:2005"), Ok(()));
+    assert_eq!(check(&built, "byr:2011"), Err("Invalid byr range".into()));
+    assert_eq!(check(&built, "hgt:120cm"), Err("Missing byr value".into()));
+    assert_eq!(
+        check(&built, "byr:1999 hgt:5ft"),
+        Err("Invalid hgt format".into())
+    );
+
+    let loaded = "byr required range 1900-2010\nhgt optional units 100-200cm"
+        .parse::<Schema>()
+        .unwrap();
+
+    assert_eq!(loaded, built);
+
+    let error = "byr required\n".parse::<Schema>().unwrap_err();
+    assert_eq!(error.line(), Some(1));
+    assert!("byr maybe any".parse::<Schema>().is_err());
+    assert!("hgt required units 1-2".parse::<Schema>().is_err());
+    assert!("ecl required one-of".parse::<Schema>().is_err());
+
+    let error = "byr required any\n\niyr required range 5-1".parse::<Schema>();
+    let error = error.unwrap_err();
+    assert_eq!((error.line(), error.message()), (Some(3), "Inverted range"));
+    assert!("hgt required units 150-193cm 76-59in"
+        .parse::<Schema>()
+        .is_err());
+
+    let error = "byr required any\nbyr optional any".parse::<Schema>();
+    let error = error.unwrap_err();
+    assert_eq!(
+        (error.line(), error.message()),
+        (Some(2), "Duplicate field")
+    );
+}
+
+#[test]
+fn test_rules() {
+    let schema = Schema::default();
+
+    assert_eq!(
+        check(
+            &schema,
+            "byr:2002 iyr:2010 eyr:2020 hgt:60in hcl:#123abc ecl:brn pid:000000001"
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        check(
+            &schema,
+            "byr:2003 iyr:2010 eyr:2020 hgt:60in hcl:#123abc ecl:brn pid:000000001"
+        ),
+        Err("Invalid byr range".into())
+    );
+    assert_eq!(
+        check(
+            &schema,
+            "byr:2002 iyr:2010 eyr:2020 hgt:190in hcl:#123abc ecl:brn pid:000000001"
+        ),
+        Err("Invalid hgt range".into())
+    );
+    assert_eq!(
+        check(
+            &schema,
+            "byr:2002 iyr:2010 eyr:2020 hgt:190 hcl:#123abc ecl:brn pid:000000001"
+        ),
+        Err("Invalid hgt format".into())
+    );
+    assert_eq!(
+        check(
+            &schema,
+            "byr:2002 iyr:2010 eyr:2020 hgt:60in hcl:#123abz ecl:brn pid:000000001"
+        ),
+        Err("Invalid hcl character".into())
+    );
+    assert_eq!(
+        check(
+            &schema,
+            "byr:2002 iyr:2010 eyr:2020 hgt:60in hcl:123abc ecl:brn pid:000000001"
+        ),
+        Err("Missing hcl prefix".into())
+    );
+    assert_eq!(
+        check(
+            &schema,
+            "byr:2002 iyr:2010 eyr:2020 hgt:60in hcl:#123abc ecl:wat pid:000000001"
+        ),
+        Err("Invalid ecl value".into())
+    );
+    assert_eq!(
+        check(
+            &schema,
+            "byr:2002 iyr:2010 eyr:2020 hgt:60in hcl:#123abc ecl:brn pid:0123456789"
+        ),
+        Err("Invalid pid length".into())
+    );
+    assert_eq!(
+        check(
+            &schema,
+            "byr:20x2 iyr:2010 eyr:2020 hgt:sixin hcl:#123abc ecl:brn pid:000000001"
+        ),
+        Err("Invalid byr number".into())
+    );
+}
+
+#[test]
+fn test_report() {
+    let database = Database::parse(INVALID, Schema::default()).unwrap();
+    let failures = database.failures();
+
+    let spans = failures
+        .iter()
+        .map(|failure| (failure.index, failure.lines));
+    assert_eq!(
+        spans.collect::<Vec<_>>(),
+        [(1, (1, 2)), (2, (4, 6)), (3, (8, 9)), (4, (11, 13))]
+    );
+
+    let messages = failures[3].errors.iter().map(|error| error.message());
+    assert_eq!(
+        messages.collect::<Vec<_>>(),
+        [
+            "Invalid byr range",
+            "Invalid iyr range",
+            "Invalid eyr range",
+            "Invalid hgt range",
+            "Missing hcl prefix",
+            "Invalid ecl value",
+            "Invalid pid length",
+        ]
+    );
+
+    let hgt = &failures[3].errors[3];
+    assert_eq!((hgt.line(), hgt.text()), (Some(11), Some("59cm")));
+
+    let summary = summarize(&failures);
+    assert_eq!(summary[0], ("Invalid eyr range".to_string(), 3));
+    assert_eq!(summary.iter().map(|(_, count)| count).sum::<usize>(), 12);
+
+    let missing = Database::parse("byr:1990\n\n\n  \nhgt:1", Schema::default()).unwrap();
+    let failures = missing.failures();
+    assert_eq!(failures[0].errors.len(), 6);
+    assert_eq!(failures[1].lines, (5, 5));
+    assert_eq!(failures[1].errors.len(), 7);
+
+    let error = Database::parse("byr:1990\n\nhgt:1\nxyz:2", Schema::default()).unwrap_err();
+    assert_eq!((error.line(), error.text()), (Some(4), Some("xyz")));
+
+    let garbled = Database::parse("byr:x iyr:y hgt:zcm", Schema::default()).unwrap();
+    let summary = summarize(&garbled.failures());
+    assert!(summary.contains(&("Invalid byr number".to_string(), 1)));
+    assert!(summary.contains(&("Invalid hgt number".to_string(), 1)));
+}
@@ -0,0 +1,149 @@
+use advent_core::error::parse_lines;
+use advent_core::Error;
+use advent_core::Result;
+use advent_core::Solver;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+#[cfg(test)]
+mod tests;
+
+const INPUT: &str = "input/2020/05.txt";
+
+#[derive(Clone, Debug)]
+enum Bound {
+    Lower,
+    Upper,
+}
+
+impl Bound {
+    fn try_from_row(value: char) -> Result<Self> {
+        match value {
+            'F' => Ok(Bound::Lower),
+            'B' => Ok(Bound::Upper),
+            _ => Err(Error::value("Invalid row value").with_text(value)),
+        }
+    }
+
+    fn try_from_col(value: char) -> Result<Self> {
+        match value {
+            'L' => Ok(Bound::Lower),
+            'R' => Ok(Bound::Upper),
+            _ => Err(Error::value("Invalid col value").with_text(value)),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Seat {
+    row: [Bound; 7],
+    col: [Bound; 3],
+}
+
+impl Seat {
+    fn number(slice: &[Bound]) -> u16 {
+        use Bound::*;
+
+        let len = slice.len();
+
+        if len == 0 || 16 <= len {
+            panic!("Unsupported slice length");
+        }
+
+        let mut lower = 0u16;
+        let mut upper = 2u16.pow(len as u32);
+
+        let start = &slice[..len - 1];
+
+        start.iter().for_each(|spec| match spec {
+            Lower => upper -= (upper - lower) / 2,
+            Upper => lower += (upper - lower) / 2,
+        });
+
+        match slice[len - 1] {
+            Lower => lower,
+            Upper => upper - 1,
+        }
+    }
+
+    fn row_number(&self) -> u16 {
+        Self::number(&self.row)
+    }
+
+    fn col_number(&self) -> u16 {
+        Self::number(&self.col)
+    }
+
+    fn seat_number(&self) -> u32 {
+        let row = self.row_number() as u32;
+        let col = self.col_number() as u32;
+
+        row * 8 + col
+    }
+}
+
+impl FromStr for Seat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut iter = s.chars();
+
+        let row = (&mut iter)
+            .take(7)
+            .map(Bound::try_from_row)
+            .collect::<Result<Vec<_>>>()?
+            .try_into()
+            .map_err(|_| Error::syntax("Invalid row count"))?;
+
+        let col = (&mut iter)
+            .take(3)
+            .map(Bound::try_from_col)
+            .collect::<Result<Vec<_>>>()?
+            .try_into()
+            .map_err(|_| Error::syntax("Invalid col count"))?;
+
+        if iter.count() == 0 {
+            Ok(Seat { row, col })
+        } else {
+            Err(Error::syntax("Invalid value count"))
+        }
+    }
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const EVENT: u16 = 2020;
+    const DAY: u8 = 5;
+    const INPUT: &'static str = INPUT;
+
+    type Input = Vec<Seat>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut seats = parse_lines(input, |line| Seat::from_str(line.trim()))?;
+
+        seats.sort_by_key(|seat| seat.seat_number());
+
+        Ok(seats)
+    }
+
+    fn part_one(seats: &Self::Input) -> Result<Self::Answer> {
+        let last = seats.last().ok_or(Error::missing("No last seat"))?;
+
+        Ok(last.seat_number())
+    }
+
+    fn part_two(seats: &Self::Input) -> Result<Self::Answer> {
+        let lower = seats.first().ok_or(Error::missing("No first seat"))?;
+        let upper = seats.last().ok_or(Error::missing("No last seat"))?;
+        let range = lower.seat_number()..=upper.seat_number();
+
+        let (empty, _) = range
+            .zip(seats.iter())
+            .find(|(i, seat)| *i != seat.seat_number())
+            .ok_or(Error::unsolved("Could not find empty seat"))?;
+
+        Ok(empty)
+    }
+}
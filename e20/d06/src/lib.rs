@@ -0,0 +1,100 @@
+use advent_core::Error;
+use advent_core::Result;
+use advent_core::Solver;
+use std::str::FromStr;
+
+#[cfg(test)]
+mod tests;
+
+const INPUT: &str = "input/2020/06.txt";
+const BOUND: u32 = 'a' as u32;
+
+#[derive(Clone, Debug)]
+struct Sheet(u32);
+
+impl Sheet {
+    fn bitmask(chr: char) -> Result<u32> {
+        let ord = match chr {
+            chr @ 'a'..='z' => Ok(u32::from(chr)),
+            _ => Err(Error::value("Invalid character").with_text(chr)),
+        }?;
+
+        Ok(1 << (ord - BOUND))
+    }
+}
+
+impl FromStr for Sheet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let pattern = s
+            .trim()
+            .chars()
+            .map(Sheet::bitmask)
+            .try_fold(0, |acc, elem| elem.map(|bits| acc | bits))?;
+
+        Ok(Sheet(pattern))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Group(Vec<Sheet>);
+
+impl Group {
+    fn count(bits: u32) -> u32 {
+        (0..32).map(|i| (bits >> i) & 1).filter(|n| *n == 1).count() as u32
+    }
+
+    fn union(&self) -> u32 {
+        self.0.iter().fold(u32::MIN, |acc, elem| acc | elem.0)
+    }
+
+    fn intersection(&self) -> u32 {
+        self.0.iter().fold(u32::MAX, |acc, elem| acc & elem.0)
+    }
+}
+
+impl FromStr for Group {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let sheets = s
+            .trim()
+            .lines()
+            .map(Sheet::from_str)
+            .collect::<Result<_>>()?;
+
+        Ok(Group(sheets))
+    }
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const EVENT: u16 = 2020;
+    const DAY: u8 = 6;
+    const INPUT: &'static str = INPUT;
+
+    type Input = Vec<Group>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.trim().split("\n\n").map(Group::from_str).collect()
+    }
+
+    fn part_one(groups: &Self::Input) -> Result<Self::Answer> {
+        let union = groups.iter().map(Group::union).map(Group::count).sum();
+
+        Ok(union)
+    }
+
+    fn part_two(groups: &Self::Input) -> Result<Self::Answer> {
+        let intersection = groups
+            .iter()
+            .map(Group::intersection)
+            .map(Group::count)
+            .sum();
+
+        Ok(intersection)
+    }
+}
@@ -0,0 +1,193 @@
+use advent_core::error::parse_lines;
+use advent_core::Error;
+use advent_core::Result;
+use advent_core::Solver;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+#[cfg(test)]
+mod tests;
+
+const INPUT: &str = "input/2020/07.txt";
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+struct BagType(String, String);
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+struct BagRule(BagType, u32);
+
+#[derive(Clone, Debug)]
+struct RulePair(BagType, Vec<BagRule>);
+
+#[derive(Clone, Debug)]
+pub struct RuleBook(HashMap<BagType, Vec<BagRule>>);
+
+impl FromStr for BagType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let split = s.split_whitespace().collect::<Vec<&str>>();
+
+        let [modifier, color, suffix] = match split[..] {
+            [m, c, s] => Ok([m.trim(), c.trim(), s.trim()]),
+            _ => Err(Error::syntax("Invalid type syntax")),
+        }?;
+
+        if suffix == "bag" || suffix == "bags" {
+            Ok(BagType(color.into(), modifier.into()))
+        } else {
+            Err(Error::syntax("Invalid type suffix"))
+        }
+    }
+}
+
+impl FromStr for BagRule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let split = s.trim().splitn(2, ' ').collect::<Vec<&str>>();
+
+        let [num, typ] = match split[..] {
+            [n, t] => Ok([n.trim(), t.trim()]),
+            _ => Err(Error::syntax("Invalid rule syntax")),
+        }?;
+
+        Ok(BagRule(typ.parse()?, num.parse()?))
+    }
+}
+
+impl FromStr for RulePair {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let split = s
+            .trim()
+            .strip_suffix('.')
+            .ok_or(Error::syntax("Missing pair suffix"))?
+            .split("contain")
+            .collect::<Vec<&str>>();
+
+        let [key, values] = match split[..] {
+            [k, v] => Ok([k.trim(), v.trim()]),
+            _ => Err(Error::syntax("Invalid pair syntax")),
+        }?;
+
+        let key = key.parse()?;
+
+        if values == "no other bags" {
+            return Ok(RulePair(key, vec![]));
+        }
+
+        let rules = values
+            .split(',')
+            .map(BagRule::from_str)
+            .collect::<Result<_>>()?;
+
+        Ok(RulePair(key, rules))
+    }
+}
+
+impl FromStr for RuleBook {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let pairs = parse_lines(s.trim(), |line| line.parse::<RulePair>())?;
+
+        let count = pairs.len();
+
+        let map = pairs
+            .into_iter()
+            .map(|pair| (pair.0, pair.1))
+            .collect::<HashMap<_, _>>();
+
+        let complete = map.values().flatten().all(|val| map.contains_key(&val.0));
+
+        if !complete {
+            Err(Error::value("Found undefined keys"))
+        } else if count != map.len() {
+            Err(Error::value("Found duplicate keys"))
+        } else {
+            Ok(RuleBook(map))
+        }
+    }
+}
+
+impl RuleBook {
+    fn invert(&self) -> HashMap<&BagType, HashSet<&BagType>> {
+        let keys = self.0.keys();
+
+        let mut result = keys
+            .map(|key| (key, HashSet::new()))
+            .collect::<HashMap<_, HashSet<_>>>();
+
+        for (key, values) in self.0.iter() {
+            for value in values.iter() {
+                result
+                    .get_mut(&value.0)
+                    .expect("Corrupt rule book")
+                    .insert(key);
+            }
+        }
+
+        result
+    }
+
+    fn solve(&self, bag: &BagType) -> Result<HashSet<BagType>> {
+        let mut result = HashSet::new();
+        let mut remain = vec![bag];
+
+        let inverted = self.invert();
+
+        while let Some(bag) = remain.pop() {
+            let iter = match inverted.get(bag) {
+                Some(list) => Ok(list.iter()),
+                None => Err(Error::missing("Unknown bag type")),
+            }?;
+
+            remain.extend(iter.filter(|bag| result.insert(**bag)));
+        }
+
+        Ok(result.into_iter().cloned().collect())
+    }
+
+    fn count(&self, bag: &BagType) -> Result<u32> {
+        let mut iter = match self.0.get(bag) {
+            Some(item) => Ok(item.iter()),
+            None => Err(Error::missing("Unknown bag type")),
+        }?;
+
+        iter.try_fold(1, |acc, elem| Ok(acc + self.count(&elem.0)? * elem.1))
+    }
+}
+
+fn target() -> BagType {
+    BagType("gold".into(), "shiny".into())
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const EVENT: u16 = 2020;
+    const DAY: u8 = 7;
+    const INPUT: &'static str = INPUT;
+
+    type Input = RuleBook;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(book: &Self::Input) -> Result<Self::Answer> {
+        let solve = book.solve(&target())?;
+
+        Ok(solve.len() as u32)
+    }
+
+    fn part_two(book: &Self::Input) -> Result<Self::Answer> {
+        let count = book.count(&target())?;
+
+        Ok(count - 1)
+    }
+}
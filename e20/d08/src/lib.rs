@@ -0,0 +1,191 @@
+use advent_core::error::parse_lines;
+use advent_core::Error;
+use advent_core::Result;
+use advent_core::Solver;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::mem::replace;
+use std::str::FromStr;
+
+#[cfg(test)]
+mod tests;
+
+const INPUT: &str = "input/2020/08.txt";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Instruction {
+    Acc(i32),
+    Jmp(i32),
+    Nop(i32),
+}
+
+#[derive(Clone, Debug)]
+pub struct Program(Vec<Instruction>);
+
+#[derive(Clone, Debug)]
+enum State {
+    Running,
+    Looping,
+    Exiting,
+}
+
+#[derive(Clone, Debug)]
+struct Machine {
+    ac: i32,
+    pc: i32,
+    program: Program,
+    seen: BTreeSet<i32>,
+    state: State,
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let split = s.trim().split(' ').collect::<Vec<_>>();
+
+        use Instruction::*;
+
+        match split[..] {
+            ["acc", val] => Ok(Acc(val.parse()?)),
+            ["jmp", val] => Ok(Jmp(val.parse()?)),
+            ["nop", val] => Ok(Nop(val.parse()?)),
+            _ => Err(Error::syntax("Invalid instruction").with_text(s.trim())),
+        }
+    }
+}
+
+impl FromStr for Program {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let ins = parse_lines(s.trim(), Instruction::from_str)?;
+
+        Ok(Program(ins))
+    }
+}
+
+impl Program {
+    fn get(&self, index: impl TryInto<usize>) -> Option<&Instruction> {
+        self.0.get(index.try_into().ok()?)
+    }
+
+    fn swap(&mut self, index: impl TryInto<usize>, repl: Instruction) -> Option<Instruction> {
+        let index = index.try_into().ok()?;
+        let target = self.0.get_mut(index)?;
+
+        Some(replace(target, repl))
+    }
+}
+
+impl Display for Machine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Machine({:?}[{}]: {})", self.state, self.pc, self.ac)
+    }
+}
+
+impl From<Program> for Machine {
+    fn from(value: Program) -> Self {
+        Machine {
+            ac: 0,
+            pc: 0,
+            program: value,
+            seen: BTreeSet::new(),
+            state: State::Running,
+        }
+    }
+}
+
+impl Machine {
+    fn step(&mut self) -> State {
+        use Instruction::*;
+
+        let Some(op) = self.program.get(self.pc) else {
+            self.state = State::Exiting;
+            return self.state.clone();
+        };
+
+        match op {
+            Acc(num) => {
+                self.ac += num;
+                self.pc += 1;
+            }
+            Jmp(num) => self.pc += num,
+            Nop(_) => self.pc += 1,
+        };
+
+        if self.seen.insert(self.pc) {
+            self.state = State::Running;
+        } else {
+            self.state = State::Looping;
+        }
+
+        self.state.clone()
+    }
+
+    fn run(&mut self) -> State {
+        while matches!(self.step(), State::Running) {}
+        self.state.clone()
+    }
+}
+
+impl Machine {
+    fn repair(program: &Program) -> Result<Machine> {
+        use Instruction::*;
+
+        let mut machine = Machine::from(program.clone());
+
+        if !matches!(machine.run(), State::Looping) {
+            return Err(Error::unsolved("Initial program never looped"));
+        }
+
+        for num in machine.seen {
+            let repl = match program.get(num) {
+                None => return Err(Error::unsolved("Invalid run")),
+                Some(Jmp(val)) => Nop(*val),
+                Some(Nop(val)) => Jmp(*val),
+                Some(_) => continue,
+            };
+
+            let mut trial = program.clone();
+            trial
+                .swap(num, repl)
+                .ok_or(Error::unsolved("Invalid swap"))?;
+            machine = Machine::from(trial);
+
+            if matches!(machine.run(), State::Exiting) {
+                return Ok(machine);
+            }
+        }
+
+        Err(Error::unsolved("Could not repair program"))
+    }
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const EVENT: u16 = 2020;
+    const DAY: u8 = 8;
+    const INPUT: &'static str = INPUT;
+
+    type Input = Program;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Program::from_str(input)
+    }
+
+    fn part_one(program: &Self::Input) -> Result<Self::Answer> {
+        let mut machine = Machine::from(program.clone());
+
+        match machine.run() {
+            State::Looping => Ok(machine.ac),
+            _ => Err(Error::unsolved("Initial program never looped")),
+        }
+    }
+
+    fn part_two(program: &Self::Input) -> Result<Self::Answer> {
+        Ok(Machine::repair(program)?.ac)
+    }
+}
@@ -0,0 +1,89 @@
+use advent_core::Error;
+use advent_core::Result;
+use advent_core::Solver;
+
+#[cfg(test)]
+mod tests;
+
+const INPUT: &str = "input/2023/01.txt";
+const VARIANTS: &[(&str, char); 9] = &[
+    ("one", '1'),
+    ("two", '2'),
+    ("three", '3'),
+    ("four", '4'),
+    ("five", '5'),
+    ("six", '6'),
+    ("seven", '7'),
+    ("eight", '8'),
+    ("nine", '9'),
+];
+
+fn extract_digit(input: &str, variants: &[(&str, char)]) -> Option<char> {
+    if let Some(chr) = input.chars().next() {
+        if chr.is_ascii_digit() {
+            return Some(chr);
+        }
+    }
+
+    for (prefix, value) in variants {
+        if input.starts_with(prefix) {
+            return Some(*value);
+        }
+    }
+
+    None
+}
+
+fn find_value_with(input: &str, variants: &[(&str, char)]) -> Option<i32> {
+    let mut digits = (0..input.len())
+        .filter(|i| input.is_char_boundary(*i))
+        .map(|i| input.split_at(i).1)
+        .filter_map(|s| extract_digit(s, variants));
+
+    let first = digits.next()?;
+    let last = digits.next_back().unwrap_or(first);
+    let number = String::from_iter([first, last]);
+
+    Some(number.parse().unwrap())
+}
+
+fn find_value(input: &str) -> Option<i32> {
+    find_value_with(input, VARIANTS)
+}
+
+fn scan_lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim).filter(|s| !s.is_empty())
+}
+
+fn sum_values_with(input: &str, variants: &[(&str, char)]) -> Option<i32> {
+    scan_lines(input)
+        .map(|s| find_value_with(s, variants))
+        .sum()
+}
+
+fn sum_values(input: &str) -> Option<i32> {
+    scan_lines(input).map(find_value).sum()
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const EVENT: u16 = 2023;
+    const DAY: u8 = 1;
+    const INPUT: &'static str = INPUT;
+
+    type Input = String;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_owned())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer> {
+        sum_values_with(input, &[]).ok_or(Error::unsolved("Could not find calibration value"))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer> {
+        sum_values(input).ok_or(Error::unsolved("Could not find calibration value"))
+    }
+}
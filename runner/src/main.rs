@@ -0,0 +1,316 @@
+use advent_core::answers::digest;
+use advent_core::answers::Answers;
+use advent_core::answers::Status;
+use advent_core::args::Args;
+use advent_core::bench::Baseline;
+use advent_core::bench::Bench;
+use advent_core::input::Source;
+use advent_core::output::Format;
+use advent_core::solver::Day;
+use advent_core::solver::Solution;
+use advent_core::Error;
+use advent_core::Result;
+use std::fmt::Write;
+use std::path::Path;
+use std::process;
+
+#[cfg(test)]
+mod tests;
+
+const DAYS: &[Day] = &[
+    Day::new::<e20d01::Puzzle>(),
+    Day::new::<e20d02::Puzzle>(),
+    Day::new::<e20d03::Puzzle>(),
+    Day::new::<e20d04::Puzzle>(),
+    Day::new::<e20d05::Puzzle>(),
+    Day::new::<e20d06::Puzzle>(),
+    Day::new::<e20d07::Puzzle>(),
+    Day::new::<e20d08::Puzzle>(),
+    Day::new::<e23d01::Puzzle>(),
+];
+
+const ANSWERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.tsv");
+const OPTIONS: &[&str] = &[
+    "format",
+    "check",
+    "record",
+    "answers",
+    "bench",
+    "runs",
+    "baseline",
+    "save",
+    "threshold",
+];
+const USAGE: &str = "Usage: advent [--format=text|json|tsv] [--check|--record] [--answers=<path>] \
+    [--bench] [--runs=<n>] [--baseline=<path>] [--save=<path>] [--threshold=<percent>] \
+    all | <event> [<day> [<input>]]";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    Print(Format),
+    Check,
+    Record,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Report {
+    passed: usize,
+    missing: usize,
+    mismatched: usize,
+    regressed: usize,
+    failed: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Selection<'a> {
+    event: Option<u16>,
+    day: Option<u8>,
+    input: Option<&'a str>,
+}
+
+impl<'a> Selection<'a> {
+    fn parse(args: &'a [String]) -> Result<Self> {
+        let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+        let (event, day, input) = match args[..] {
+            ["all"] => (None, None, None),
+            [event] => (Some(event), None, None),
+            [event, day] => (Some(event), Some(day), None),
+            [event, day, input] => (Some(event), Some(day), Some(input)),
+            _ => return Err(Error::syntax(USAGE)),
+        };
+
+        let event = match event {
+            Some(text) => Some(
+                text.parse()
+                    .map_err(|_| Error::value("Invalid event").with_text(text))?,
+            ),
+            None => None,
+        };
+
+        let day = match day {
+            Some(text) => Some(
+                text.parse()
+                    .map_err(|_| Error::value("Invalid day").with_text(text))?,
+            ),
+            None => None,
+        };
+
+        Ok(Selection { event, day, input })
+    }
+
+    fn matches(&self, day: &Day) -> bool {
+        self.event.is_none_or(|event| event == day.event)
+            && self.day.is_none_or(|num| num == day.day)
+    }
+}
+
+impl Report {
+    fn add(&mut self, status: &Status) {
+        match status {
+            Status::Pass => self.passed += 1,
+            Status::Missing { .. } => self.missing += 1,
+            Status::Mismatch { .. } => self.mismatched += 1,
+        }
+    }
+
+    fn success(&self) -> bool {
+        self.missing == 0 && self.mismatched == 0 && self.regressed == 0 && self.failed == 0
+    }
+}
+
+fn solve(day: &Day, source: &Source) -> Result<(Solution, String)> {
+    let text = source.read().map_err(Error::from)?;
+    let solution = (day.solve)(&text)?;
+
+    Ok((solution, digest(&text)))
+}
+
+fn run(days: &[&Day], input: Option<&str>, mode: Mode, answers: &mut Answers) -> Report {
+    let mut report = Report::default();
+
+    if let Mode::Print(format) = mode {
+        if let Some(header) = format.header() {
+            println!("{header}");
+        }
+    }
+
+    for day in days {
+        let source = Source::new(input, day.input);
+
+        let (solution, digest) = match solve(day, &source) {
+            Ok(result) => result,
+            Err(error) => {
+                eprintln!("{} {:02} failed: {error}", day.event, day.day);
+                report.failed += 1;
+                continue;
+            }
+        };
+
+        match mode {
+            Mode::Print(format) => {
+                for record in solution.records() {
+                    println!("{}", format.record(&record));
+                }
+            }
+            Mode::Check => {
+                let statuses = answers.check(&solution, &digest);
+
+                for (record, status) in solution.records().iter().zip(statuses) {
+                    println!(
+                        "{} {:02} part {}: {status}",
+                        day.event, day.day, record.part
+                    );
+                    report.add(&status);
+                }
+            }
+            Mode::Record => answers.record(&solution, &digest),
+        }
+    }
+
+    report
+}
+
+fn bench(
+    days: &[&Day],
+    input: Option<&str>,
+    runs: usize,
+    baseline: &Baseline,
+    threshold: f64,
+) -> (Report, Baseline) {
+    let mut report = Report::default();
+    let mut current = Baseline::default();
+
+    for day in days {
+        let source = Source::new(input, day.input);
+
+        let result = source
+            .read()
+            .map_err(Error::from)
+            .and_then(|text| Bench::run(day, &text, runs));
+
+        let bench = match result {
+            Ok(bench) => bench,
+            Err(error) => {
+                eprintln!("{} {:02} failed: {error}", day.event, day.day);
+                report.failed += 1;
+                continue;
+            }
+        };
+
+        for (stage, stats) in bench.stages {
+            let mut line = format!(
+                "{} {:02} {stage:<5} min {:>10.2?} median {:>10.2?} max {:>10.2?}",
+                day.event, day.day, stats.min, stats.median, stats.max,
+            );
+
+            if let Some(cmp) = baseline.compare(&bench, stage, threshold / 100.0) {
+                let change = cmp.change * 100.0;
+                write!(line, " baseline {:>10.2?} {change:+.1}%", cmp.baseline).unwrap();
+
+                if cmp.regressed {
+                    line.push_str(" REGRESSION");
+                    report.regressed += 1;
+                }
+            }
+
+            println!("{line}");
+        }
+
+        current.record(&bench);
+    }
+
+    (report, current)
+}
+
+fn exit(error: impl std::fmt::Display) -> ! {
+    eprintln!("{error}");
+    process::exit(2);
+}
+
+fn main() {
+    let args = Args::from_env();
+
+    if let Err(error) = args.allow(OPTIONS) {
+        exit(format!("{error}\n{USAGE}"));
+    }
+
+    let format = match args.parse::<Format>("format") {
+        Ok(format) => format.unwrap_or_default(),
+        Err(error) => exit(error),
+    };
+
+    let runs = match args.parse::<usize>("runs") {
+        Ok(runs) => runs.unwrap_or(10),
+        Err(error) => exit(error),
+    };
+
+    let threshold = match args.parse::<f64>("threshold") {
+        Ok(threshold) => threshold.unwrap_or(10.0),
+        Err(error) => exit(error),
+    };
+
+    let mode = match (args.flag("check"), args.flag("record"), args.flag("bench")) {
+        (false, false, false) => Some(Mode::Print(format)),
+        (true, false, false) => Some(Mode::Check),
+        (false, true, false) => Some(Mode::Record),
+        (false, false, true) => None,
+        _ => exit(USAGE),
+    };
+
+    let selection = match Selection::parse(args.positional()) {
+        Ok(selection) => selection,
+        Err(error) => exit(error),
+    };
+
+    let days = DAYS
+        .iter()
+        .filter(|day| selection.matches(day))
+        .collect::<Vec<_>>();
+
+    if days.is_empty() {
+        exit("No solver matches the selection");
+    }
+
+    let Some(mode) = mode else {
+        let baseline = match args.value("baseline") {
+            Some(path) => Baseline::load(path).unwrap_or_else(|error| exit(error)),
+            None => Baseline::default(),
+        };
+
+        let (report, current) = bench(&days, selection.input, runs, &baseline, threshold);
+
+        if let Some(path) = args.value("save") {
+            current.save(path).unwrap_or_else(|error| exit(error));
+        }
+
+        process::exit(i32::from(!report.success()));
+    };
+
+    let path = args.value("answers").unwrap_or(ANSWERS);
+
+    let mut answers = match mode {
+        Mode::Print(_) => Answers::default(),
+        Mode::Record if !Path::new(path).exists() => Answers::default(),
+        _ => Answers::load(path).unwrap_or_else(|error| exit(error)),
+    };
+
+    let report = run(&days, selection.input, mode, &mut answers);
+
+    match mode {
+        Mode::Print(_) => (),
+        Mode::Check => println!(
+            "{} passed, {} mismatched, {} missing, {} failed",
+            report.passed, report.mismatched, report.missing, report.failed,
+        ),
+        Mode::Record => {
+            if let Err(error) = answers.save(path) {
+                exit(error);
+            }
+        }
+    }
+
+    if !report.success() {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::run;
+use crate::Mode;
+use crate::Report;
+use crate::Selection;
+use crate::ANSWERS;
+use crate::DAYS;
+use advent_core::answers::Answers;
+
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn test_all() {
+    let args = args(&["all"]);
+    let selection = Selection::parse(&args).unwrap();
+
+    assert_eq!(
+        DAYS.iter().filter(|d| selection.matches(d)).count(),
+        DAYS.len()
+    );
+}
+
+#[test]
+fn test_day() {
+    let args = args(&["2020", "7", "-"]);
+    let selection = Selection::parse(&args).unwrap();
+
+    let days = DAYS
+        .iter()
+        .filter(|d| selection.matches(d))
+        .map(|d| (d.event, d.day))
+        .collect::<Vec<_>>();
+
+    assert_eq!(days, vec![(2020, 7)]);
+    assert_eq!(selection.input, Some("-"));
+}
+
+#[test]
+fn test_invalid() {
+    assert!(Selection::parse(&args(&[])).is_err());
+    assert!(Selection::parse(&args(&["twenty"])).is_err());
+    assert!(Selection::parse(&args(&["2020", "1", "a", "b"])).is_err());
+}
+
+#[test]
+fn test_answers() {
+    let mut answers = Answers::load(ANSWERS).unwrap();
+    let days = DAYS.iter().collect::<Vec<_>>();
+    let report = run(&days, None, Mode::Check, &mut answers);
+
+    let expect = Report {
+        passed: DAYS.len() * 2,
+        ..Report::default()
+    };
+
+    assert_eq!(report, expect);
+    assert!(report.success());
+
+    let missing = Report {
+        missing: 1,
+        ..expect
+    };
+
+    assert!(!missing.success());
+}